@@ -0,0 +1,193 @@
+use anyhow::Result;
+use colored::Colorize;
+use log::{trace, warn};
+use rbx_dom_weak::types::{Content, ContentId, ContentType, Variant};
+use serde::{Deserialize, Serialize};
+use std::{
+	collections::{BTreeMap, HashMap},
+	fs,
+	path::{Path, PathBuf},
+};
+
+use crate::{
+	core::{meta::Context, snapshot::Snapshot},
+	open_cloud::{self, Creator},
+	vasc_info, Properties,
+};
+
+/// Directory that is scanned for assets to upload
+pub const ASSETS_DIR: &str = "assets";
+/// File that stores IDs of the uploaded assets
+pub const LOCKFILE_NAME: &str = "assets.lock";
+
+const ASSET_PREFIX: &str = "rbxassetid://";
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct LockedAsset {
+	pub id: u64,
+	pub hash: String,
+}
+
+/// Uploaded assets keyed by their path relative to the assets directory
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct Lockfile {
+	#[serde(default)]
+	pub assets: BTreeMap<String, LockedAsset>,
+}
+
+impl Lockfile {
+	pub fn load(workspace_dir: &Path) -> Result<Self> {
+		let path = workspace_dir.join(LOCKFILE_NAME);
+
+		if !path.exists() {
+			return Ok(Self::default());
+		}
+
+		Ok(toml::from_str(&fs::read_to_string(path)?)?)
+	}
+
+	pub fn save(&self, workspace_dir: &Path) -> Result<()> {
+		fs::write(workspace_dir.join(LOCKFILE_NAME), toml::to_string(self)?)?;
+
+		Ok(())
+	}
+
+	pub fn asset_ids(&self) -> HashMap<String, u64> {
+		self.assets
+			.iter()
+			.map(|(path, asset)| (path.clone(), asset.id))
+			.collect()
+	}
+}
+
+/// Returns Open Cloud asset type and MIME type of the file
+fn get_asset_type(path: &Path) -> Option<(&'static str, &'static str)> {
+	let extension = path.extension()?.to_str()?.to_lowercase();
+
+	let asset_type = match extension.as_str() {
+		"png" => ("Decal", "image/png"),
+		"jpg" | "jpeg" => ("Decal", "image/jpeg"),
+		"bmp" => ("Decal", "image/bmp"),
+		"tga" => ("Decal", "image/tga"),
+		"mp3" => ("Audio", "audio/mpeg"),
+		"ogg" => ("Audio", "audio/ogg"),
+		"wav" => ("Audio", "audio/wav"),
+		"flac" => ("Audio", "audio/flac"),
+		"fbx" => ("Model", "model/fbx"),
+		_ => return None,
+	};
+
+	Some(asset_type)
+}
+
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+	for entry in fs::read_dir(dir)? {
+		let path = entry?.path();
+
+		if path.is_dir() {
+			collect_files(&path, files)?;
+		} else if get_asset_type(&path).is_some() {
+			files.push(path);
+		}
+	}
+
+	Ok(())
+}
+
+/// Upload new and modified assets of the workspace and
+/// record their IDs in the lockfile, returns number of uploads
+pub fn sync(workspace_dir: &Path, api_key: &str, creator: Creator) -> Result<usize> {
+	let assets_dir = workspace_dir.join(ASSETS_DIR);
+
+	if !assets_dir.exists() {
+		trace!("Asset sync skipped: {} directory does not exist", ASSETS_DIR);
+		return Ok(0);
+	}
+
+	let mut lockfile = Lockfile::load(workspace_dir)?;
+	let mut files = Vec::new();
+	let mut uploaded = 0;
+
+	collect_files(&assets_dir, &mut files)?;
+	files.sort();
+
+	for path in files {
+		let key = path
+			.strip_prefix(&assets_dir)?
+			.components()
+			.map(|component| component.as_os_str().to_string_lossy())
+			.collect::<Vec<_>>()
+			.join("/");
+
+		let hash = blake3::hash(&fs::read(&path)?).to_hex().to_string();
+
+		if lockfile.assets.get(&key).is_some_and(|asset| asset.hash == hash) {
+			continue;
+		}
+
+		let (asset_type, mime) = get_asset_type(&path).unwrap();
+		let id = open_cloud::upload_asset(api_key, &path, asset_type, mime, creator)?;
+
+		vasc_info!("Uploaded {} as {}", key.bold(), format!("{ASSET_PREFIX}{id}").bold());
+
+		lockfile.assets.insert(key, LockedAsset { id, hash });
+		uploaded += 1;
+
+		// Saved after every upload so finished ones are not lost on failure
+		lockfile.save(workspace_dir)?;
+	}
+
+	Ok(uploaded)
+}
+
+/// Returns IDs of the uploaded assets, empty if there is no lockfile
+pub fn get_asset_ids(workspace_dir: &Path) -> HashMap<String, u64> {
+	match Lockfile::load(workspace_dir) {
+		Ok(lockfile) => lockfile.asset_ids(),
+		Err(err) => {
+			warn!("Failed to load {}: {}", LOCKFILE_NAME, err);
+			HashMap::new()
+		}
+	}
+}
+
+/// Replace asset references in the snapshot and all its descendants
+pub fn substitute(snapshot: &mut Snapshot, context: &Context) {
+	substitute_properties(&mut snapshot.properties, context);
+
+	for child in &mut snapshot.children {
+		substitute(child, context);
+	}
+}
+
+/// Replace `rbxassetid://<path>` references with IDs of the uploaded assets
+pub fn substitute_properties(properties: &mut Properties, context: &Context) {
+	if context.asset_ids().is_empty() {
+		return;
+	}
+
+	for value in properties.values_mut() {
+		let reference = match value {
+			Variant::Content(content) => match content.value() {
+				ContentType::Uri(uri) => uri.as_str(),
+				_ => continue,
+			},
+			Variant::ContentId(content) => content.as_str(),
+			_ => continue,
+		};
+
+		let Some(id) = reference
+			.strip_prefix(ASSET_PREFIX)
+			.and_then(|path| context.asset_ids().get(path))
+		else {
+			continue;
+		};
+
+		let uri = format!("{ASSET_PREFIX}{id}");
+
+		*value = match value {
+			Variant::Content(_) => Content::from(uri).into(),
+			_ => ContentId::from(uri).into(),
+		};
+	}
+}
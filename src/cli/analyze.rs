@@ -0,0 +1,82 @@
+use anyhow::{bail, Result};
+use clap::Parser;
+use colored::Colorize;
+use std::{env, fs, path::PathBuf};
+
+use crate::{
+	config::Config,
+	core::Core,
+	ext::PathExt,
+	integration,
+	project::{self, Project},
+	vasc_error, vasc_info, vasc_warn,
+};
+
+/// Type check project scripts with luau-lsp
+#[derive(Parser)]
+pub struct Analyze {
+	/// Project path
+	#[arg()]
+	project: Option<PathBuf>,
+
+	/// Download the latest Roblox type definitions
+	#[arg(short, long)]
+	update_definitions: bool,
+}
+
+impl Analyze {
+	pub fn main(self) -> Result<()> {
+		let project_path = project::resolve(self.project.unwrap_or_default())?;
+
+		if !project_path.exists() {
+			bail!(
+				"No project files found in {}",
+				project_path.get_parent().to_string().bold()
+			);
+		}
+
+		Config::load_workspace(project_path.get_parent());
+
+		let project = Project::load(&project_path)?;
+		let workspace_dir = project.workspace_dir.clone();
+
+		let sourcemap = env::temp_dir()
+			.join("vasc")
+			.join(format!("{}.sourcemap.json", project.name));
+
+		fs::create_dir_all(sourcemap.get_parent())?;
+
+		let core = Core::new(project, false)?;
+		core.sourcemap(Some(sourcemap.clone()), false)?;
+
+		let definitions = integration::get_type_definitions(self.update_definitions)?;
+
+		vasc_info!("Analyzing project: {}", project_path.to_string().bold());
+
+		let Some(output) = integration::analyze(&workspace_dir, &sourcemap, &definitions)? else {
+			bail!("Analysis could not run because luau-lsp is not installed");
+		};
+
+		// luau-lsp reports diagnostics to both streams depending on the version
+		let diagnostics = [output.stdout, output.stderr]
+			.iter()
+			.map(|stream| String::from_utf8_lossy(stream).trim().to_owned())
+			.filter(|stream| !stream.is_empty())
+			.collect::<Vec<_>>()
+			.join("\n");
+
+		if !output.status.success() {
+			vasc_error!("Project has type errors:\n{}", diagnostics);
+			bail!("Analysis failed");
+		}
+
+		if !diagnostics.is_empty() {
+			vasc_warn!("Project has warnings:\n{}", diagnostics);
+			return Ok(());
+		}
+
+		vasc_info!("No problems found!");
+
+		Ok(())
+	}
+}
@@ -0,0 +1,85 @@
+use anyhow::{bail, Result};
+use clap::Parser;
+use colored::Colorize;
+use std::{path::PathBuf, thread, time::Duration};
+
+use crate::{
+	assets::{self, ASSETS_DIR, LOCKFILE_NAME},
+	config::Config,
+	ext::PathExt,
+	open_cloud::{self, Creator},
+	project, vasc_error, vasc_info,
+};
+
+const WATCH_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Upload assets from the `assets` directory using Open Cloud
+#[derive(Parser)]
+pub struct Assets {
+	/// Project path
+	#[arg()]
+	project: Option<PathBuf>,
+
+	/// Upload assets every time they change
+	#[arg(short, long)]
+	watch: bool,
+
+	/// Asset creator (user:<id> or group:<id>), overrides `asset_creator` setting
+	#[arg(short, long)]
+	creator: Option<String>,
+}
+
+impl Assets {
+	pub fn main(self) -> Result<()> {
+		let project_path = project::resolve(self.project.unwrap_or_default())?;
+		let workspace_dir = project_path.get_parent();
+
+		Config::load_workspace(workspace_dir);
+
+		let creator = self.creator.unwrap_or_else(|| Config::new().asset_creator.clone());
+
+		if creator.is_empty() {
+			bail!(
+				"Asset creator is not set. Pass {} option or set {} setting",
+				"--creator".bold(),
+				"asset_creator".bold()
+			);
+		}
+
+		let creator: Creator = creator.parse()?;
+		let api_key = open_cloud::get_api_key()?;
+
+		if !workspace_dir.join(ASSETS_DIR).exists() {
+			bail!(
+				"No {} directory found in {}",
+				ASSETS_DIR.bold(),
+				workspace_dir.to_string().bold()
+			);
+		}
+
+		if self.watch {
+			vasc_info!("Watching {} for changes..", ASSETS_DIR.bold());
+
+			loop {
+				match assets::sync(workspace_dir, &api_key, creator) {
+					Ok(0) => {}
+					Ok(uploaded) => vasc_info!("Uploaded {} assets", uploaded.to_string().bold()),
+					Err(err) => vasc_error!("Failed to upload assets: {}", err),
+				}
+
+				thread::sleep(WATCH_INTERVAL);
+			}
+		}
+
+		match assets::sync(workspace_dir, &api_key, creator)? {
+			0 => vasc_info!("All assets are up to date!"),
+			uploaded => vasc_info!(
+				"Uploaded {} assets, their IDs were saved to {}",
+				uploaded.to_string().bold(),
+				LOCKFILE_NAME.bold()
+			),
+		}
+
+		Ok(())
+	}
+}
@@ -2,18 +2,23 @@ use anyhow::{bail, Result};
 use clap::Parser;
 use colored::Colorize;
 use log::{debug, info};
-use roblox_install::RobloxStudio;
-use std::{fs, path::PathBuf, process};
+use std::{
+	fs,
+	path::{Path, PathBuf},
+	process,
+};
 
 use crate::{
 	argon_info,
 	config::Config,
+	constants::BUILD_DEBOUNCE_TIME,
 	core::Core,
 	ext::PathExt,
 	integration,
 	program::{Program, ProgramName},
 	project::{self, Project},
-	sessions,
+	sessions::{self, Session},
+	util,
 };
 
 /// Build project into Roblox binary or XML place or model
@@ -35,6 +40,10 @@ pub struct Build {
 	#[arg(short, long)]
 	watch: bool,
 
+	/// Copy built file to this path after every build (e.g. Studio autoload folder)
+	#[arg(short, long)]
+	copy_to: Option<PathBuf>,
+
 	/// Generate sourcemap every time files change
 	#[arg(short, long)]
 	sourcemap: bool,
@@ -92,7 +101,8 @@ impl Build {
 				bail!("Cannot build plugin from place project");
 			}
 
-			let plugins_path = RobloxStudio::locate()?.plugins_path().to_owned();
+			// Same location the Vasc plugin is installed to
+			let plugins_path = util::get_plugin_paths()?[0].get_parent().to_owned();
 			let ext = if xml { "rbxmx" } else { "rbxm" };
 
 			plugins_path.join(format!("{}.{}", project.name, ext))
@@ -178,6 +188,10 @@ impl Build {
 			path.to_string().bold()
 		);
 
+		if let Some(destination) = &self.copy_to {
+			Self::copy_output(&path, destination)?;
+		}
+
 		if let Some(path) = &sourcemap_path {
 			core.sourcemap(Some(path.clone()), false)?;
 
@@ -194,10 +208,11 @@ impl Build {
 					.current_dir(working_dir)
 					.arg("rbxtsc")
 					.arg("--watch")
-					.spawn()?;
+					.spawn_tracked()?;
 			}
 
-			sessions::add(self.session, None, None, process::id(), config.run_async)?;
+			let session = Session::new(process::id()).with_project(&project_path);
+			sessions::add(self.session, session, config.run_async)?;
 
 			argon_info!("Watching for changes..");
 
@@ -206,10 +221,15 @@ impl Build {
 
 			loop {
 				let _message = queue.get_change(0).unwrap();
+				let batched = queue.drain_changes(0, BUILD_DEBOUNCE_TIME).unwrap();
 
-				info!("Rebuilding project..");
+				info!("Rebuilding project after {} changes..", batched + 1);
 				core.build(&path, xml)?;
 
+				if let Some(destination) = &self.copy_to {
+					Self::copy_output(&path, destination)?;
+				}
+
 				if let Some(path) = &sourcemap_path {
 					info!("Regenerating sourcemap..");
 					core.sourcemap(Some(path.clone()), false)?;
@@ -220,6 +240,21 @@ impl Build {
 		Ok(())
 	}
 
+	fn copy_output(path: &Path, destination: &Path) -> Result<()> {
+		let destination = if destination.is_dir() {
+			destination.join(path.get_name())
+		} else {
+			destination.to_owned()
+		};
+
+		fs::create_dir_all(destination.get_parent())?;
+		fs::copy(path, &destination)?;
+
+		debug!("Copied build output to: {}", destination.display());
+
+		Ok(())
+	}
+
 	fn get_default_file(&self, project: &Project) -> PathBuf {
 		let ext = if project.is_place() {
 			if self.xml {
@@ -256,6 +291,11 @@ impl Build {
 			args.push("--watch".into())
 		}
 
+		if let Some(copy_to) = self.copy_to {
+			args.push("--copy-to".into());
+			args.push(copy_to.to_string())
+		}
+
 		if self.sourcemap {
 			args.push("--sourcemap".into())
 		}
@@ -1,10 +1,10 @@
-use anyhow::Result;
+use anyhow::{bail, Result};
 use clap::Parser;
-use reqwest::{blocking::Client, header::CONTENT_TYPE};
-use serde::Serialize;
+use reqwest::header::CONTENT_TYPE;
+use serde::{Deserialize, Serialize};
 use std::{fs, path::MAIN_SEPARATOR};
 
-use crate::{sessions, vasc_error, vasc_info};
+use crate::{constants::EXEC_TIMEOUT, sessions, vasc_error, vasc_info, vasc_warn};
 
 /// Execute Luau code in Roblox Studio (requires running session)
 #[derive(Parser)]
@@ -25,6 +25,10 @@ pub struct Exec {
 	#[arg(short, long)]
 	standalone: bool,
 
+	/// Wait for the code to finish and print its output
+	#[arg(short, long)]
+	wait: bool,
+
 	/// Server host name
 	#[arg(short = 'H', long)]
 	host: Option<String>,
@@ -46,14 +50,16 @@ impl Exec {
 			// TODO: Implement standalone mode
 			vasc_error!("Standalone mode is not implemented yet!");
 		} else if let Some(session) = sessions::get(self.session, self.host, self.port)? {
-			let address = session.get_address().or_else(|| {
+			let session = if session.get_address().is_some() {
+				Some(session)
+			} else {
 				sessions::get_all()
 					.unwrap_or_default()
-					.into_iter()
-					.find_map(|(_, session)| session.get_address())
-			});
+					.into_values()
+					.find(|session| session.get_address().is_some())
+			};
 
-			if let Some(address) = address {
+			if let Some((address, session)) = session.and_then(|s| s.get_address().map(|a| (a, s))) {
 				let url = format!("{address}/exec");
 
 				let body = rmp_serde::to_vec(&Request {
@@ -63,15 +69,23 @@ impl Exec {
 					} else {
 						false
 					},
+					wait: self.wait,
 				})?;
 
-				let response = Client::default()
+				let response = session
+					.client()
 					.post(url)
 					.header(CONTENT_TYPE, "application/msgpack")
+					// Leave the server enough time to report that the execution timed out
+					.timeout(EXEC_TIMEOUT * 2)
 					.body(body)
 					.send();
 
 				match response {
+					Ok(response) if self.wait => {
+						let response = response.error_for_status()?;
+						Self::print_output(rmp_serde::from_slice(&response.bytes()?)?)?;
+					}
 					Ok(_) => vasc_info!("Code executed successfully!"),
 					Err(err) => vasc_error!("Code execution failed: {}", err),
 				}
@@ -90,6 +104,31 @@ impl Exec {
 		Ok(())
 	}
 
+	fn print_output(result: ExecResult) -> Result<()> {
+		let mut failed = false;
+
+		for output in result.output {
+			match output.kind {
+				OutputKind::Print => vasc_info!("{}", output.message),
+				OutputKind::Warn => vasc_warn!("{}", output.message),
+				OutputKind::Error => {
+					vasc_error!("{}", output.message);
+					failed = true;
+				}
+			}
+		}
+
+		if !result.completed {
+			bail!("Timed out waiting for the code to finish");
+		}
+
+		if failed {
+			bail!("Code execution finished with errors");
+		}
+
+		Ok(())
+	}
+
 	fn is_path(&self) -> bool {
 		if self.code.contains('\n') {
 			return false;
@@ -107,4 +146,25 @@ impl Exec {
 struct Request {
 	code: String,
 	focus: bool,
+	wait: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum OutputKind {
+	Print,
+	Warn,
+	Error,
+}
+
+#[derive(Deserialize)]
+struct Output {
+	kind: OutputKind,
+	message: String,
+}
+
+#[derive(Deserialize)]
+struct ExecResult {
+	output: Vec<Output>,
+	completed: bool,
 }
@@ -8,6 +8,8 @@ use crate::{
 	config::Config,
 	ext::PathExt,
 	logger, project, stats,
+	templates::{self, Variables},
+	util,
 	workspace::{self, WorkspaceConfig, WorkspaceLicense},
 };
 
@@ -80,15 +82,48 @@ pub struct Init {
     	action = ArgAction::Set,
     )]
 	ts: Option<bool>,
+
+	/// Include example scripts
+	#[arg(
+		short,
+        long,
+        default_missing_value("true"),
+		hide_possible_values = true,
+        num_args(0..=1),
+    	action = ArgAction::Set,
+    )]
+	examples: Option<bool>,
+
+	/// Skip interactive prompts and use default values
+	#[arg(long)]
+	defaults: bool,
+
+	/// Template variable in `name=value` format (can be used multiple times)
+	#[arg(long = "var", value_name = "NAME=VALUE")]
+	vars: Vec<String>,
 }
 
 impl Init {
-	pub fn main(self) -> Result<()> {
+	pub fn main(mut self) -> Result<()> {
+		let interactive = !self.defaults && !util::env_yes();
+
+		if interactive && self.project.is_none() {
+			let name = logger::input("Project name (leave empty to use current directory)", "");
+
+			if !name.is_empty() {
+				self.project = Some(PathBuf::from(name));
+			}
+		}
+
 		let project_path = project::resolve(self.project.clone().unwrap_or_default())?;
 
 		Config::load_workspace(project_path.get_parent());
 		let config = Config::new();
 
+		if interactive {
+			self.prompt(&config)?;
+		}
+
 		let project = self.project.unwrap_or_default();
 		let template = self.template.unwrap_or(config.template.clone());
 		let git = self.git.unwrap_or(config.use_git);
@@ -96,22 +131,38 @@ impl Init {
 		let selene = self.selene.unwrap_or(config.use_selene);
 		let docs = self.docs.unwrap_or(config.include_docs);
 		let ts = self.ts.unwrap_or(config.ts_mode);
+		let examples = self.examples.unwrap_or(config.include_examples);
 
 		let license = WorkspaceLicense {
 			force: self.license.is_some(),
 			inner: &self.license.unwrap_or(config.license.clone()),
 		};
 
+		let author = if config.author.is_empty() {
+			util::get_username()
+		} else {
+			config.author.clone()
+		};
+
+		let variables = Variables::new()
+			.with_interactive(interactive)
+			.with_pairs(&self.vars)?
+			.with("project_name", project_path.get_parent().get_name())
+			.with("author", &author);
+
 		let mut workspace_config = WorkspaceConfig {
 			project: &project,
 			template: &template,
 			license,
+			author: &author,
 			git,
 			wally,
 			selene,
 			docs,
 			rojo_mode: config.rojo_mode,
 			use_lua: config.lua_extension,
+			examples,
+			variables,
 		};
 
 		if ts {
@@ -140,8 +191,50 @@ impl Init {
 
 		argon_info!("Successfully initialized project: {}", project_path.to_string().bold());
 
+		if let Some((_, manifest)) = templates::get(&template)? {
+			templates::run_hooks(&manifest, project_path.get_parent())?;
+		}
+
 		stats::projects_created(1);
 
 		Ok(())
 	}
+
+	fn prompt(&mut self, config: &Config) -> Result<()> {
+		if self.template.is_none() {
+			let templates = templates::get_all()?;
+
+			if !templates.is_empty() {
+				let items = templates
+					.iter()
+					.map(|template| {
+						if template.description.is_empty() {
+							template.name.clone()
+						} else {
+							format!("{} - {}", template.name, template.description)
+						}
+					})
+					.collect::<Vec<_>>();
+
+				let default = templates
+					.iter()
+					.position(|template| template.name == config.template)
+					.unwrap_or_default();
+
+				let index = logger::select("Template", &items, default);
+
+				self.template = Some(templates[index].name.clone());
+			}
+		}
+
+		if self.git.is_none() {
+			self.git = Some(logger::prompt("Initialize Git repository?", config.use_git));
+		}
+
+		if self.examples.is_none() {
+			self.examples = Some(logger::prompt("Include example code?", config.include_examples));
+		}
+
+		Ok(())
+	}
 }
@@ -0,0 +1,46 @@
+use anyhow::{bail, Result};
+use clap::Parser;
+use colored::Colorize;
+use std::path::PathBuf;
+
+use crate::{ext::PathExt, integration, project, vasc_info};
+
+/// Install Wally packages of the project
+#[derive(Parser)]
+pub struct Install {
+	/// Project path
+	#[arg()]
+	project: Option<PathBuf>,
+}
+
+impl Install {
+	pub fn main(self) -> Result<()> {
+		let project_path = project::resolve(self.project.unwrap_or_default())?;
+		let workspace_dir = project_path.get_parent();
+
+		if !workspace_dir.join("wally.toml").exists() {
+			bail!(
+				"No {} found in {}, this project does not use Wally",
+				"wally.toml".bold(),
+				workspace_dir.to_string().bold()
+			);
+		}
+
+		vasc_info!("Installing Wally packages..");
+
+		if integration::install_packages(workspace_dir)? {
+			vasc_info!(
+				"Installed packages into: {}",
+				integration::PACKAGE_DIRS
+					.iter()
+					.filter(|dir| workspace_dir.join(dir).exists())
+					.copied()
+					.collect::<Vec<_>>()
+					.join(", ")
+					.bold()
+			);
+		}
+
+		Ok(())
+	}
+}
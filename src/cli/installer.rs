@@ -0,0 +1,49 @@
+use anyhow::Result;
+use clap::{Parser, ValueEnum};
+
+use crate::{ext::PathExt, installer, logger, vasc_info};
+
+/// Manage Vasc installation
+#[derive(Parser)]
+pub struct Installer {
+	/// Whether to `clean` installed plugin, templates and cached files
+	#[arg(hide_possible_values = true)]
+	mode: Option<InstallerMode>,
+}
+
+impl Installer {
+	pub fn main(self) -> Result<()> {
+		match self.mode.unwrap_or_default() {
+			InstallerMode::Clean => {
+				if !logger::prompt(
+					"This will remove installed plugin, templates and cached files (config is preserved). Continue?",
+					true,
+				) {
+					return Ok(());
+				}
+
+				let (removed, stale) = installer::clean()?;
+
+				if removed.is_empty() && stale == 0 {
+					vasc_info!("Nothing to clean");
+				} else {
+					let mut removed = removed.iter().map(|path| path.to_string()).collect::<Vec<_>>();
+
+					if stale > 0 {
+						removed.push(format!("{stale} stale session(s) from sessions.toml"));
+					}
+
+					vasc_info!("Removed:\n\n{}", removed.join("\n"));
+				}
+			}
+		}
+
+		Ok(())
+	}
+}
+
+#[derive(Clone, Default, ValueEnum)]
+enum InstallerMode {
+	#[default]
+	Clean,
+}
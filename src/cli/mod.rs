@@ -3,21 +3,30 @@ use clap::{ColorChoice, Parser, Subcommand};
 use clap_verbosity_flag::Verbosity;
 use env_logger::fmt::WriteStyle;
 use log::LevelFilter;
-use std::env;
+use std::{env, path::PathBuf};
 
-use crate::util;
+use crate::{logger::LogFormat, util};
 
+mod analyze;
+mod assets;
 mod build;
 mod config;
 mod debug;
 mod doc;
 mod exec;
 mod init;
+mod install;
+mod installer;
 mod plugin;
+mod ps;
+mod publish;
+mod pull;
 mod serve;
 mod sourcemap;
 mod stop;
 mod studio;
+mod telemetry;
+mod template;
 mod update;
 
 macro_rules! about {
@@ -57,6 +66,21 @@ pub struct Cli {
 	#[arg(short = 'B', long, global = true)]
 	backtrace: bool,
 
+	/// Log output format: text, json
+	#[arg(
+		long,
+		global = true,
+		value_name = "FORMAT",
+		default_value = "text",
+		hide_default_value = true,
+		hide_possible_values = true
+	)]
+	log_format: LogFormat,
+
+	/// Write logs to the given file in addition to the console
+	#[arg(long, global = true, value_name = "PATH")]
+	log_file: Option<PathBuf>,
+
 	#[arg(long, hide = true, global = true)]
 	profile: bool,
 
@@ -106,6 +130,22 @@ impl Cli {
 		self.verbose.log_level_filter()
 	}
 
+	pub fn log_file(&self) -> Option<PathBuf> {
+		if let Ok(path) = env::var("RUST_LOG_FILE") {
+			return Some(PathBuf::from(path));
+		}
+
+		self.log_file.clone()
+	}
+
+	pub fn log_format(&self) -> LogFormat {
+		if env::var("RUST_LOG_FORMAT").is_ok() {
+			return util::env_log_format();
+		}
+
+		self.log_format
+	}
+
 	pub fn log_style(&self) -> WriteStyle {
 		if env::var("RUST_LOG_STYLE").is_ok() {
 			return util::env_log_style();
@@ -118,19 +158,54 @@ impl Cli {
 		}
 	}
 
+	pub fn command_name(&self) -> &'static str {
+		match self.command {
+			Commands::Init(_) => "init",
+			Commands::Install(_) => "install",
+			Commands::Serve(_) => "serve",
+			Commands::Build(_) => "build",
+			Commands::Sourcemap(_) => "sourcemap",
+			Commands::Analyze(_) => "analyze",
+			Commands::Stop(_) => "stop",
+			Commands::Ps(_) => "ps",
+			Commands::Studio(_) => "studio",
+			Commands::Assets(_) => "assets",
+			Commands::Publish(_) => "publish",
+			Commands::Debug(_) => "debug",
+			Commands::Exec(_) => "exec",
+			Commands::Pull(_) => "pull",
+			Commands::Update(_) => "update",
+			Commands::Plugin(_) => "plugin",
+			Commands::Template(_) => "template",
+			Commands::Config(_) => "config",
+			Commands::Installer(_) => "installer",
+			Commands::Telemetry(_) => "telemetry",
+			Commands::Doc(_) => "doc",
+		}
+	}
+
 	pub fn main(self) -> Result<()> {
 		match self.command {
 			Commands::Init(command) => command.main(),
+			Commands::Install(command) => command.main(),
 			Commands::Serve(command) => command.main(),
 			Commands::Build(command) => command.main(),
 			Commands::Sourcemap(command) => command.main(),
+			Commands::Analyze(command) => command.main(),
 			Commands::Stop(command) => command.main(),
+			Commands::Ps(command) => command.main(),
 			Commands::Studio(command) => command.main(),
+			Commands::Assets(command) => command.main(),
+			Commands::Publish(command) => command.main(),
 			Commands::Debug(command) => command.main(),
 			Commands::Exec(command) => command.main(),
+			Commands::Pull(command) => command.main(),
 			Commands::Update(command) => command.main(),
 			Commands::Plugin(command) => command.main(),
+			Commands::Template(command) => command.main(),
 			Commands::Config(command) => command.main(),
+			Commands::Installer(command) => command.main(),
+			Commands::Telemetry(command) => command.main(),
 			Commands::Doc(command) => command.main(),
 		}
 	}
@@ -139,15 +214,24 @@ impl Cli {
 #[derive(Subcommand)]
 pub enum Commands {
 	Init(init::Init),
+	Install(install::Install),
 	Serve(serve::Serve),
 	Build(build::Build),
 	Sourcemap(sourcemap::Sourcemap),
+	Analyze(analyze::Analyze),
 	Stop(stop::Stop),
+	Ps(ps::Ps),
 	Studio(studio::Studio),
+	Assets(assets::Assets),
+	Publish(publish::Publish),
 	Debug(debug::Debug),
 	Exec(exec::Exec),
+	Pull(pull::Pull),
 	Update(update::Update),
 	Plugin(plugin::Plugin),
+	Template(template::Template),
 	Config(config::Config),
+	Installer(installer::Installer),
+	Telemetry(telemetry::Telemetry),
 	Doc(doc::Doc),
 }
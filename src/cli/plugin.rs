@@ -2,25 +2,39 @@ use anyhow::Result;
 use clap::{Parser, ValueEnum};
 use std::{fs, path::PathBuf};
 
-use crate::{argon_info, config::Config, ext::PathExt, installer, util};
+use super::build::Build;
+use crate::{argon_info, config::Config, ext::PathExt, installer, updater, util, vasc_info};
 
-/// Install Argon Roblox Studio plugin locally
+/// Install Argon Roblox Studio plugin locally or build your own one
 #[derive(Parser)]
 pub struct Plugin {
-	/// Whether to `install` or `uninstall` the plugin
+	/// Whether to `install`, `uninstall` or `build` the plugin
 	#[arg(hide_possible_values = true)]
 	mode: Option<PluginMode>,
-	/// Custom plugin installation path
+	/// Custom plugin installation path (project path when building)
 	#[arg()]
 	path: Option<PathBuf>,
+	/// Use beta channel, installed next to the stable plugin
+	#[arg(short, long)]
+	beta: bool,
+	/// Place built plugin into the Studio plugins folder
+	#[arg(short, long)]
+	install: bool,
+	/// Rebuild plugin every time files change
+	#[arg(short, long)]
+	watch: bool,
 }
 
 impl Plugin {
 	pub fn main(self) -> Result<()> {
-		let plugin_path = if let Some(path) = self.path {
+		if let Some(PluginMode::Build) = self.mode {
+			return self.build();
+		}
+
+		let plugin_paths = if let Some(path) = self.path {
 			let smart_paths = Config::new().smart_paths;
 
-			if path.is_dir() || (smart_paths && (path.extension().is_none())) {
+			let path = if path.is_dir() || (smart_paths && (path.extension().is_none())) {
 				if !smart_paths || path.get_name().to_lowercase() != "argon" {
 					path.join("Argon.rbxm")
 				} else {
@@ -28,24 +42,67 @@ impl Plugin {
 				}
 			} else {
 				path
-			}
+			};
+
+			vec![path]
 		} else {
-			util::get_plugin_path()?
+			util::get_plugin_paths()?
 		};
 
-		match self.mode.unwrap_or_default() {
-			PluginMode::Install => {
-				argon_info!("Installing Argon plugin..");
-				installer::install_plugin(&plugin_path, true)?;
-			}
-			PluginMode::Uninstall => {
-				argon_info!("Uninstalling Argon plugin..");
-				fs::remove_file(plugin_path)?;
+		let mode = self.mode.unwrap_or_default();
+
+		for plugin_path in plugin_paths {
+			match mode {
+				PluginMode::Install if self.beta => {
+					vasc_info!("Installing Argon beta plugin..");
+					installer::install_beta_plugin(&installer::get_beta_path(&plugin_path), true)?;
+				}
+				PluginMode::Install => {
+					argon_info!("Installing Argon plugin..");
+					installer::install_plugin(&plugin_path, true)?;
+				}
+				PluginMode::Build => unreachable!(),
+				PluginMode::Uninstall => {
+					let plugin_path = if self.beta {
+						installer::get_beta_path(&plugin_path)
+					} else {
+						plugin_path
+					};
+
+					if plugin_path.exists() {
+						argon_info!("Uninstalling Argon plugin..");
+						fs::remove_file(plugin_path)?;
+					}
+				}
 			}
 		}
 
+		if self.beta && matches!(mode, PluginMode::Uninstall) {
+			let mut status = updater::get_status()?;
+			status.beta_plugin_version = None;
+			updater::set_status(&status)?;
+		}
+
 		Ok(())
 	}
+
+	fn build(self) -> Result<()> {
+		let mut args = vec![String::from("build")];
+
+		if let Some(path) = self.path {
+			args.push(path.to_string());
+		}
+
+		if self.install {
+			args.push("--plugin".into());
+		}
+
+		if self.watch {
+			args.push("--watch".into());
+		}
+
+		Build::try_parse_from(args)?.main()
+	}
 }
 
 #[derive(Clone, Default, ValueEnum)]
@@ -53,4 +110,5 @@ enum PluginMode {
 	#[default]
 	Install,
 	Uninstall,
+	Build,
 }
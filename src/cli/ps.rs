@@ -0,0 +1,36 @@
+use anyhow::Result;
+use clap::Parser;
+
+use crate::{ext::PathExt, logger::Table, sessions, vasc_info, vasc_warn};
+
+/// List all running Argon sessions
+#[derive(Parser)]
+pub struct Ps {}
+
+impl Ps {
+	pub fn main(self) -> Result<()> {
+		let sessions = sessions::get_all()?;
+
+		if sessions.is_empty() {
+			vasc_warn!("There are no running sessions");
+			return Ok(());
+		}
+
+		let mut table = Table::new();
+		table.set_header(vec!["ID", "Host", "Port", "PID", "Project"]);
+
+		for (id, session) in sessions {
+			table.add_row(vec![
+				id,
+				session.host.unwrap_or("None".into()),
+				session.port.map(|p| p.to_string()).unwrap_or("None".into()),
+				session.pid.to_string(),
+				session.project.map(|p| p.to_string()).unwrap_or("None".into()),
+			]);
+		}
+
+		vasc_info!("All running sessions:\n\n{}", table);
+
+		Ok(())
+	}
+}
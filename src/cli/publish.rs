@@ -0,0 +1,117 @@
+use anyhow::{bail, Context, Result};
+use clap::Parser;
+use colored::Colorize;
+use std::{env, fs, path::PathBuf};
+
+use crate::{
+	config::Config,
+	core::Core,
+	ext::PathExt,
+	open_cloud,
+	project::{self, Project, PublishProfile},
+	vasc_info,
+};
+
+/// Build the project and publish it using Open Cloud
+#[derive(Parser)]
+pub struct Publish {
+	/// Project path
+	#[arg()]
+	project: Option<PathBuf>,
+
+	/// Publish profile from the project file
+	#[arg(long)]
+	profile: Option<String>,
+
+	/// Universe (experience) ID, overrides the profile
+	#[arg(short, long)]
+	universe: Option<u64>,
+
+	/// Place ID, overrides the profile
+	#[arg(short, long)]
+	place: Option<u64>,
+
+	/// Only save the new version without publishing it
+	#[arg(short, long)]
+	save: bool,
+}
+
+impl Publish {
+	pub fn main(self) -> Result<()> {
+		let project_path = project::resolve(self.project.clone().unwrap_or_default())?;
+
+		Config::load_workspace(project_path.get_parent());
+
+		if !project_path.exists() {
+			bail!(
+				"No project files found in {}",
+				project_path.get_parent().to_string().bold()
+			);
+		}
+
+		let project = Project::load(&project_path)?;
+
+		if !project.is_place() {
+			bail!("Cannot publish non-place project!");
+		}
+
+		let profile = self.get_profile(&project)?;
+		let api_key = open_cloud::get_api_key()?;
+
+		let path = env::temp_dir().join("vasc").join(format!("{}.rbxl", project.name));
+		fs::create_dir_all(path.get_parent())?;
+
+		vasc_info!("Building project..");
+
+		Core::new(project, false)?.build(&path, false)?;
+
+		vasc_info!(
+			"Uploading to place {} of universe {}..",
+			profile.place_id.to_string().bold(),
+			profile.universe_id.to_string().bold()
+		);
+
+		let version = open_cloud::publish_place(&api_key, profile.universe_id, profile.place_id, &path, !self.save)?;
+
+		vasc_info!(
+			"Successfully {} project: {}, version: {}",
+			if self.save { "saved" } else { "published" },
+			project_path.to_string().bold(),
+			version.to_string().bold()
+		);
+
+		fs::remove_file(path).ok();
+
+		Ok(())
+	}
+
+	/// Returns selected profile with IDs overridden by the command options,
+	/// the only profile in the project is used if none was selected
+	fn get_profile(&self, project: &Project) -> Result<PublishProfile> {
+		let profile =
+			match &self.profile {
+				Some(name) => {
+					Some(project.publish.get(name).cloned().with_context(|| {
+						format!("Publish profile {} does not exist in the project file", name.bold())
+					})?)
+				}
+				None if project.publish.len() == 1 => project.publish.values().next().cloned(),
+				None => project.publish.get("default").cloned(),
+			};
+
+		let universe_id = self
+			.universe
+			.or_else(|| profile.as_ref().map(|profile| profile.universe_id));
+		let place_id = self.place.or_else(|| profile.as_ref().map(|profile| profile.place_id));
+
+		match (universe_id, place_id) {
+			(Some(universe_id), Some(place_id)) => Ok(PublishProfile { universe_id, place_id }),
+			_ => bail!(
+				"Universe and place IDs are required. Pass {} and {} options or add a profile to the {} field of the project",
+				"--universe".bold(),
+				"--place".bold(),
+				"publish".bold()
+			),
+		}
+	}
+}
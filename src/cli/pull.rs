@@ -0,0 +1,76 @@
+use anyhow::Result;
+use clap::Parser;
+use colored::Colorize;
+use reqwest::header::CONTENT_TYPE;
+use serde::Serialize;
+
+use crate::{sessions, vasc_error, vasc_info};
+
+/// Pull instances from Roblox Studio into files (requires running session)
+#[derive(Parser)]
+pub struct Pull {
+	/// Instances to pull, e.g. `ReplicatedStorage.Shared` (all synced instances if empty)
+	#[arg()]
+	paths: Vec<String>,
+
+	/// Session identifier
+	#[arg(short, long)]
+	session: Option<String>,
+
+	/// Server host name
+	#[arg(short = 'H', long)]
+	host: Option<String>,
+
+	/// Server port
+	#[arg(short = 'P', long)]
+	port: Option<u16>,
+}
+
+impl Pull {
+	pub fn main(self) -> Result<()> {
+		let Some(session) = sessions::get(self.session, self.host, self.port)? else {
+			vasc_error!("Pull failed: no running session was found");
+			return Ok(());
+		};
+
+		let Some(address) = session.get_address() else {
+			vasc_error!("Pull failed: running session does not have an address");
+			return Ok(());
+		};
+
+		let body = rmp_serde::to_vec(&Request {
+			paths: self.paths.clone(),
+		})?;
+
+		let response = session
+			.client()
+			.post(format!("{address}/pull"))
+			.header(CONTENT_TYPE, "application/msgpack")
+			.body(body)
+			.send();
+
+		match response {
+			Ok(response) if response.status().is_success() => {
+				let target = if self.paths.is_empty() {
+					"all synced instances".to_owned()
+				} else {
+					self.paths.join(", ")
+				};
+
+				vasc_info!(
+					"Requested {} from Roblox Studio, instances will be written by the running session",
+					target.bold()
+				);
+			}
+			Ok(response) => vasc_error!("Pull failed: {}", response.text()?),
+			Err(err) => vasc_error!("Pull failed: {}", err),
+		}
+
+		Ok(())
+	}
+}
+
+#[derive(Serialize)]
+struct Request {
+	paths: Vec<String>,
+}
@@ -13,7 +13,8 @@ use crate::{
 	program::{Program, ProgramName},
 	project::{self, Project},
 	server::{self, Server},
-	sessions,
+	sessions::{self, Session},
+	vasc_info,
 };
 
 /// Start local server and listen for file changes
@@ -43,6 +44,10 @@ pub struct Serve {
 	#[arg(short, long)]
 	ts: bool,
 
+	/// File containing the session token, `VASC_TOKEN` is used if not set
+	#[arg(long)]
+	token_file: Option<PathBuf>,
+
 	/// Run Argon asynchronously
 	#[arg(short = 'A', long = "async")]
 	run_async: bool,
@@ -55,6 +60,7 @@ pub struct Serve {
 impl Serve {
 	pub fn main(self) -> Result<()> {
 		let project_path = project::resolve(self.project.clone().unwrap_or_default())?;
+		let project_is_implicit = self.project.is_none();
 
 		Config::load_workspace(project_path.get_parent());
 		let config = Config::new();
@@ -100,7 +106,7 @@ impl Serve {
 				.current_dir(working_dir)
 				.arg("rbxtsc")
 				.arg("--watch")
-				.spawn()?;
+				.spawn_tracked()?;
 
 			if child.is_none() {
 				return Ok(());
@@ -108,28 +114,32 @@ impl Serve {
 		}
 
 		let core = Core::new(project, true)?;
-		let host = self.host.unwrap_or(core.host().unwrap_or(config.host.clone()));
-		let mut port = self.port.unwrap_or(core.port().unwrap_or(config.port));
+		let host = server::normalize_host(&self.host.unwrap_or(core.host().unwrap_or(config.host.clone())));
+		let port = self.port.unwrap_or(core.port().unwrap_or(config.port));
 
-		if !server::is_port_free(&host, port) {
-			if config.scan_ports {
-				let new_port = server::get_free_port(&host, port);
+		let listener = match server::bind(&host, port) {
+			Ok(listener) => listener,
+			Err(_) if config.scan_ports => {
+				let listener = server::bind_free_port(&host, port)?;
 
 				argon_warn!(
 					"Port {} is already in use, using {} instead!",
 					port.to_string().bold(),
-					new_port.to_string().bold()
+					listener.local_addr()?.port().to_string().bold()
 				);
 
-				port = new_port;
-			} else {
+				listener
+			}
+			Err(_) => {
 				bail!(
 					"Port {} is already in use! Enable {} setting to use first available port automatically",
 					port.to_string().bold(),
 					"scan_ports".bold()
 				);
 			}
-		}
+		};
+
+		let port = listener.local_addr()?.port();
 
 		let core = Arc::new(core);
 
@@ -156,22 +166,55 @@ impl Serve {
 			});
 		}
 
-		sessions::add(
-			self.session,
-			Some(host.clone()),
-			Some(port),
-			process::id(),
-			config.run_async,
-		)?;
+		let mut server = Server::new(core, listener);
+		let mut cert = None;
+
+		if let Some(token) = server::read_token(self.token_file.as_deref())? {
+			server = server.with_token(&token);
+		}
+
+		if config.tls {
+			let (tls, self_signed) = server::tls::load_config(&config, &host)?;
+
+			server = server.with_tls(tls);
+			cert = self_signed;
+		}
 
-		let server = Server::new(core, &host, port);
+		let mut session = Session::new(process::id())
+			.with_address(&host, port)
+			.with_token(server.token())
+			.with_project(&project_path)
+			.with_tls(config.tls);
+
+		if let Some(cert) = &cert {
+			session = session.with_cert(cert);
+		}
+
+		sessions::add(self.session, session, config.run_async)?;
 
 		argon_info!(
 			"Serving on: {}, project: {}",
-			server::format_address(&host, port).bold(),
+			server::format_address(&host, port, config.tls).bold(),
 			project_path.to_string().bold()
 		);
 
+		if project_is_implicit {
+			let places = project::get_places(&project_path)?;
+
+			if places.len() > 1 {
+				vasc_info!(
+					"Workspace contains other places: {}, serve them by passing their project path",
+					places
+						.iter()
+						.filter(|place| **place != project_path)
+						.map(|place| place.get_name())
+						.collect::<Vec<_>>()
+						.join(", ")
+						.bold()
+				);
+			}
+		}
+
 		server.start()?;
 
 		Ok(())
@@ -206,6 +249,11 @@ impl Serve {
 			args.push("--ts".into());
 		}
 
+		if let Some(token_file) = self.token_file {
+			args.push("--token-file".into());
+			args.push(token_file.to_string());
+		}
+
 		Program::new(ProgramName::Argon).args(args).spawn()?;
 
 		Ok(())
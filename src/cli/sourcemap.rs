@@ -11,7 +11,7 @@ use crate::{
 	ext::PathExt,
 	program::{Program, ProgramName},
 	project::{self, Project},
-	sessions,
+	sessions::{self, Session},
 };
 
 /// Generate JSON sourcemap of the project
@@ -90,7 +90,8 @@ impl Sourcemap {
 		}
 
 		if self.watch {
-			sessions::add(self.session, None, None, process::id(), config.run_async)?;
+			let session = Session::new(process::id()).with_project(&project_path);
+			sessions::add(self.session, session, config.run_async)?;
 
 			if self.output.is_some() {
 				argon_info!("Watching for changes..");
@@ -1,9 +1,14 @@
 use anyhow::Result;
 use clap::Parser;
 use colored::Colorize;
-use reqwest::blocking::Client;
 
-use crate::{argon_info, argon_warn, logger::Table, sessions, util};
+use super::ps::Ps;
+use crate::{
+	argon_info, argon_warn,
+	server::TOKEN_HEADER,
+	sessions::{self, Session},
+	util,
+};
 
 /// Stop Argon session by address, ID or all running sessions
 #[derive(Parser)]
@@ -32,28 +37,7 @@ pub struct Stop {
 impl Stop {
 	pub fn main(self) -> Result<()> {
 		if self.list {
-			let sessions = sessions::get_all()?;
-
-			if sessions.is_empty() {
-				argon_warn!("There are no running sessions");
-				return Ok(());
-			}
-
-			let mut table = Table::new();
-			table.set_header(vec!["ID", "Host", "Port", "PID"]);
-
-			for (id, session) in sessions {
-				table.add_row(vec![
-					id,
-					session.host.unwrap_or("None".into()),
-					session.port.map(|p| p.to_string()).unwrap_or("None".into()),
-					session.pid.to_string(),
-				]);
-			}
-
-			argon_info!("All running sessions:\n\n{}", table);
-
-			return Ok(());
+			return Ps {}.main();
 		}
 
 		if self.all {
@@ -65,11 +49,7 @@ impl Stop {
 			}
 
 			for (_, session) in sessions {
-				if let Some(address) = session.get_address() {
-					Self::make_request(&address, session.pid);
-				} else {
-					Self::kill_process(session.pid);
-				}
+				Self::stop_session(&session);
 			}
 
 			return sessions::remove_all();
@@ -77,11 +57,7 @@ impl Stop {
 
 		if self.session.is_empty() {
 			if let Some(session) = sessions::get(None, self.host, self.port)? {
-				if let Some(address) = session.get_address() {
-					Self::make_request(&address, session.pid);
-				} else {
-					Self::kill_process(session.pid);
-				}
+				Self::stop_session(&session);
 
 				sessions::remove(&session)?;
 			} else {
@@ -94,11 +70,7 @@ impl Stop {
 				argon_warn!("There are no running sessions with provided IDs");
 			} else {
 				for session in sessions.values() {
-					if let Some(address) = session.get_address() {
-						Self::make_request(&address, session.pid);
-					} else {
-						Self::kill_process(session.pid);
-					}
+					Self::stop_session(session);
 				}
 
 				sessions::remove_multiple(&self.session)?;
@@ -108,13 +80,28 @@ impl Stop {
 		Ok(())
 	}
 
-	fn make_request(address: &String, pid: u32) {
+	fn stop_session(session: &Session) {
+		if let Some(address) = session.get_address() {
+			Self::make_request(&address, session);
+		} else {
+			Self::kill_process(session.pid);
+		}
+	}
+
+	fn make_request(address: &String, session: &Session) {
 		let url = format!("{address}/stop");
+		let mut request = session.client().post(url);
 
-		match Client::new().post(url).send() {
-			Ok(_) => argon_info!("Stopped Argon session with address: {}", address.bold()),
-			Err(_) => {
-				Self::kill_process(pid);
+		if let Some(token) = &session.token {
+			request = request.header(TOKEN_HEADER, token);
+		}
+
+		match request.send() {
+			Ok(response) if response.status().is_success() => {
+				argon_info!("Stopped Argon session with address: {}", address.bold())
+			}
+			_ => {
+				Self::kill_process(session.pid);
 			}
 		}
 	}
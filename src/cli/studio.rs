@@ -1,19 +1,38 @@
-use anyhow::Result;
+use anyhow::{bail, Result};
 use clap::Parser;
-use std::path::PathBuf;
+use colored::Colorize;
+use std::{
+	env, fs,
+	path::{Path, PathBuf},
+};
 
-use crate::{argon_info, config::Config, ext::PathExt, studio};
+use crate::{
+	argon_info,
+	config::Config,
+	ext::PathExt,
+	program::{Program, ProgramName},
+	project::{self, Project},
+	studio, vasc_info,
+};
 
 /// Launch a new Roblox Studio instance
 #[derive(Parser)]
 pub struct Studio {
-	/// Path to place or model to open
+	/// Path to place or model to open (project path with `--build` or `--serve`)
 	#[arg()]
 	path: Option<PathBuf>,
 
 	/// Check if Roblox Studio is already running
 	#[arg(short, long)]
 	check: bool,
+
+	/// Build the project to a temporary place file and open it
+	#[arg(short, long)]
+	build: bool,
+
+	/// Start serving the project in the background
+	#[arg(short, long)]
+	serve: bool,
 }
 
 impl Studio {
@@ -23,6 +42,30 @@ impl Studio {
 			return Ok(());
 		}
 
+		if self.build || self.serve {
+			let project_path = project::resolve(self.path.clone().unwrap_or_default())?;
+
+			if !project_path.exists() {
+				bail!(
+					"No project files found in {}. Run {} to create new one",
+					project_path.get_parent().to_string().bold(),
+					"vasc init".bold(),
+				);
+			}
+
+			if self.build {
+				self.path = Some(Self::build(&project_path)?);
+			} else {
+				self.path = None;
+			}
+
+			if self.serve {
+				Program::new(ProgramName::Argon)
+					.args(["serve".into(), project_path.to_string()])
+					.spawn()?;
+			}
+		}
+
 		argon_info!("Launching Roblox Studio..");
 
 		if let Some(path) = self.path.as_ref() {
@@ -42,4 +85,35 @@ impl Studio {
 
 		Ok(())
 	}
+
+	fn build(project_path: &Path) -> Result<PathBuf> {
+		let project = Project::load(project_path)?;
+
+		if !project.is_place() {
+			bail!("Cannot open non-place project in Roblox Studio!");
+		}
+
+		let output = env::temp_dir().join("vasc").join(format!("{}.rbxl", project.name));
+		fs::create_dir_all(output.get_parent())?;
+
+		vasc_info!("Building project to: {}", output.to_string().bold());
+
+		let status = Program::new(ProgramName::Argon)
+			.message("Failed to build project")
+			.args([
+				"build".into(),
+				project_path.to_string(),
+				"--output".into(),
+				output.to_string(),
+			])
+			.spawn()?
+			.map(|mut child| child.wait())
+			.transpose()?;
+
+		if !status.is_some_and(|status| status.success()) {
+			bail!("Failed to build project, Roblox Studio was not launched");
+		}
+
+		Ok(output)
+	}
 }
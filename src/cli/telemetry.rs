@@ -0,0 +1,77 @@
+use anyhow::Result;
+use clap::{Parser, ValueEnum};
+
+use crate::{config::Config, logger::Table, telemetry, vasc_info, vasc_warn};
+
+/// View or clear locally stored telemetry events
+#[derive(Parser)]
+pub struct Telemetry {
+	/// Whether to `show` or `clear` telemetry events
+	#[arg(hide_possible_values = true)]
+	mode: Option<TelemetryMode>,
+}
+
+impl Telemetry {
+	pub fn main(self) -> Result<()> {
+		match self.mode.unwrap_or_default() {
+			TelemetryMode::Show => {
+				if !telemetry::is_available() {
+					vasc_info!("This Vasc build has no telemetry endpoint, events are not recorded");
+				} else if !Config::new().telemetry {
+					vasc_info!("Telemetry is disabled, enable it with `vasc config telemetry true`");
+				}
+
+				let telemetry = telemetry::get_telemetry()?;
+
+				if telemetry.pending.is_empty() && telemetry.sent.is_empty() {
+					vasc_warn!("There are no telemetry events");
+					return Ok(());
+				}
+
+				let mut table = Table::new();
+				table.set_header(vec![
+					"Status",
+					"Command",
+					"Duration",
+					"Success",
+					"Project size",
+					"Version",
+					"OS",
+				]);
+
+				let events = telemetry
+					.sent
+					.iter()
+					.map(|event| ("Sent", event))
+					.chain(telemetry.pending.iter().map(|event| ("Pending", event)));
+
+				for (status, event) in events {
+					table.add_row(vec![
+						status.to_owned(),
+						event.command.clone(),
+						format!("{}ms", event.duration_ms),
+						event.success.to_string(),
+						event.project_size.clone(),
+						event.version.clone(),
+						event.os.clone(),
+					]);
+				}
+
+				vasc_info!("Telemetry events:\n\n{}", table);
+			}
+			TelemetryMode::Clear => {
+				telemetry::clear()?;
+				vasc_info!("Cleared all telemetry events");
+			}
+		}
+
+		Ok(())
+	}
+}
+
+#[derive(Clone, Default, ValueEnum)]
+enum TelemetryMode {
+	#[default]
+	Show,
+	Clear,
+}
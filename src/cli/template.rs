@@ -0,0 +1,174 @@
+use anyhow::{bail, Result};
+use clap::{Parser, ValueEnum};
+use colored::Colorize;
+use std::{env, fs, path::PathBuf};
+
+use crate::{
+	constants::TEMPLATES_VERSION,
+	ext::PathExt,
+	installer,
+	logger::Table,
+	templates::{self, TemplateManifest},
+	updater, vasc_info, vasc_warn,
+};
+
+/// Manage project templates
+#[derive(Parser)]
+pub struct Template {
+	/// Whether to `list`, `add`, `remove`, `update`, `show` or `export` templates
+	#[arg(hide_possible_values = true)]
+	mode: Option<TemplateMode>,
+
+	/// Git repository URL or archive (`add`), template name or project path (`export`)
+	#[arg()]
+	target: Option<String>,
+
+	/// Git revision (branch, tag or commit) to pin templates to
+	#[arg(short, long)]
+	rev: Option<String>,
+
+	/// Output path of the exported template archive
+	#[arg(short, long)]
+	output: Option<PathBuf>,
+}
+
+impl Template {
+	pub fn main(self) -> Result<()> {
+		match self.mode.unwrap_or_default() {
+			TemplateMode::List => {
+				let templates = templates::get_all()?;
+
+				if templates.is_empty() {
+					vasc_warn!("There are no installed templates");
+					return Ok(());
+				}
+
+				let mut table = Table::new();
+				table.set_header(vec!["Name", "Description", "Version", "Source"]);
+
+				for template in templates {
+					let source = Self::format_source(&template);
+					table.add_row(vec![template.name, template.description, template.version, source]);
+				}
+
+				vasc_info!("Installed templates:\n\n{}", table);
+			}
+			TemplateMode::Add => {
+				let Some(target) = self.target else {
+					bail!("Repository URL or template archive is required to add templates");
+				};
+
+				let path = PathBuf::from(&target);
+
+				if path.is_file() {
+					let template = templates::add_from_archive(&path)?;
+					vasc_info!("Added template: {}", template.name.bold());
+				} else {
+					vasc_info!("Fetching templates from: {}", target.bold());
+
+					for template in templates::add_from_git(&target, self.rev.as_deref())? {
+						vasc_info!("Added template: {}", template.name.bold());
+					}
+				}
+			}
+			TemplateMode::Remove => {
+				let Some(name) = self.target else {
+					bail!("Template name is required to remove a template");
+				};
+
+				templates::remove(&name)?;
+
+				vasc_info!("Removed template: {}", name.bold());
+			}
+			TemplateMode::Update => {
+				let bundled = match &self.target {
+					Some(name) => templates::get(name)?.is_some_and(|(_, manifest)| manifest.is_bundled()),
+					None => true,
+				};
+
+				if bundled {
+					installer::install_templates(true)?;
+
+					let mut status = updater::get_status()?;
+					status.templates_version = TEMPLATES_VERSION;
+					updater::set_status(&status)?;
+
+					vasc_info!("Updated bundled templates");
+				}
+
+				for template in templates::update(self.target.as_deref())? {
+					vasc_info!("Updated template: {}", template.name.bold());
+				}
+			}
+			TemplateMode::Show => {
+				let Some(name) = self.target else {
+					bail!("Template name is required to show a template");
+				};
+
+				let Some((path, template)) = templates::get(&name)? else {
+					bail!("Template {} does not exist", name.bold());
+				};
+
+				let mut files = fs::read_dir(&path)?
+					.filter_map(|entry| entry.ok())
+					.map(|entry| entry.path().get_name().to_owned())
+					.filter(|name| name != templates::MANIFEST_NAME)
+					.collect::<Vec<_>>();
+
+				files.sort();
+
+				vasc_info!(
+					"Template: {}\n\nDescription: {}\nVersion: {}\nSource: {}\nPath: {}\nFiles: {}",
+					template.name.bold(),
+					template.description,
+					template.version,
+					Self::format_source(&template),
+					path.to_string(),
+					files.join(", ")
+				);
+			}
+			TemplateMode::Export => {
+				let project_dir = match self.target {
+					Some(path) => PathBuf::from(path),
+					None => env::current_dir()?,
+				};
+
+				if !project_dir.is_dir() {
+					bail!("Directory {} does not exist", project_dir.to_string().bold());
+				}
+
+				let output = self.output.unwrap_or_else(|| {
+					PathBuf::from(format!("{}.zip", project_dir.resolve().unwrap_or_default().get_name()))
+				});
+
+				let template = templates::export(&project_dir, &output)?;
+
+				vasc_info!(
+					"Exported template {} to: {}",
+					template.name.bold(),
+					output.to_string().bold()
+				);
+			}
+		}
+
+		Ok(())
+	}
+
+	fn format_source(template: &TemplateManifest) -> String {
+		match &template.rev {
+			Some(rev) => format!("{}@{}", template.source, rev),
+			None => template.source.clone(),
+		}
+	}
+}
+
+#[derive(Clone, Default, ValueEnum)]
+enum TemplateMode {
+	#[default]
+	List,
+	Add,
+	Remove,
+	Update,
+	Show,
+	Export,
+}
@@ -7,6 +7,7 @@ use log::{debug, info};
 use optfield::optfield;
 use serde::{ser::SerializeMap, Deserialize, Serialize, Serializer};
 use std::{
+	collections::HashMap,
 	env,
 	fmt::{self, Debug, Display, Formatter},
 	fs, mem,
@@ -15,7 +16,7 @@ use std::{
 };
 use toml;
 
-use crate::{argon_error, logger::Table, util};
+use crate::{argon_error, logger::Table, migrations, util};
 
 lazy_static! {
 	static ref CONFIG: RwLock<Config> = RwLock::new(Config::default());
@@ -40,10 +41,14 @@ pub struct Config {
 	pub template: String,
 	/// Default project license (SPDX identifier)
 	pub license: String,
+	/// Author name used in project templates (system username if empty)
+	pub author: String,
 	/// Include documentation in the project (README, CHANGELOG, etc.)
 	pub include_docs: bool,
 	/// Use Git for source control
 	pub use_git: bool,
+	/// Include example scripts from the template
+	pub include_examples: bool,
 	/// Use Wally for package management
 	pub use_wally: bool,
 	/// Use selene for codebase linting
@@ -53,6 +58,24 @@ pub struct Config {
 	pub run_async: bool,
 	/// Scan for the first available port if selected one is in use
 	pub scan_ports: bool,
+	/// Level at which server requests are logged (off, error, warn, info, debug, trace)
+	pub request_log_level: String,
+	/// File to append server access log to (disabled if empty)
+	pub access_log: String,
+	/// Maximum size of a single client request in MiB
+	pub max_payload_size: usize,
+	/// Maximum number of requests per second from a single IP address (0 to disable)
+	pub rate_limit: u32,
+	/// IP addresses or CIDR ranges allowed to connect, separated by commas (all if empty)
+	pub allowed_ips: String,
+	/// IP addresses or CIDR ranges refused even if allowed, separated by commas
+	pub denied_ips: String,
+	/// Serve over HTTPS using certificate from `tls_cert` and `tls_key` or a self-signed one
+	pub tls: bool,
+	/// Path to PEM encoded TLS certificate (generated automatically if empty)
+	pub tls_cert: String,
+	/// Path to PEM encoded TLS private key (generated automatically if empty)
+	pub tls_key: String,
 	/// Automatically detect project type
 	pub detect_project: bool,
 	/// Use smart path resolver when running commands
@@ -61,6 +84,10 @@ pub struct Config {
 	pub with_sourcemap: bool,
 	/// Build using XML format by default
 	pub build_xml: bool,
+	/// Owner of assets uploaded with `vasc assets` (user:<id> or group:<id>)
+	pub asset_creator: String,
+	/// File containing Open Cloud API key, used when `VASC_API_KEY` is not set
+	pub api_key_file: String,
 
 	/// Check for new Argon releases on startup
 	pub check_updates: bool,
@@ -68,8 +95,12 @@ pub struct Config {
 	pub auto_update: bool,
 	/// Install Roblox plugin locally and keep it updated
 	pub install_plugin: bool,
+	/// Additional plugin install locations, separated the same way as in PATH
+	pub plugin_paths: String,
 	/// Update default project templates when available
 	pub update_templates: bool,
+	/// Plugin release channel to install and keep updated (stable or beta)
+	pub update_channel: String,
 
 	/// Use Rojo namespace by default
 	pub rojo_mode: bool,
@@ -82,6 +113,10 @@ pub struct Config {
 	pub keep_duplicates: bool,
 	/// Move files to the bin instead of deleting them (two-way sync)
 	pub move_to_bin: bool,
+	/// Format scripts synced back from Studio with StyLua
+	pub format_on_sync: bool,
+	/// Lint scripts synced back from Studio with selene and report issues
+	pub lint_on_sync: bool,
 	/// Number of changes allowed before prompting user for confirmation
 	pub changes_threshold: usize,
 	/// Maximum number of unsynced changes before showing a warning
@@ -91,10 +126,26 @@ pub struct Config {
 	pub lua_extension: bool,
 	/// Ignore line endings when reading files to avoid script diffs
 	pub ignore_line_endings: bool,
+	/// Detect file changes by polling instead of OS events (always used on network drives)
+	pub use_polling: bool,
+	/// Interval in milliseconds between scans when polling for file changes
+	pub polling_interval: u64,
 	/// Package manager to use when running roblox-ts scripts (npm, bun, etc.)
 	pub package_manager: String,
 	/// Share anonymous Argon usage statistics with the community
 	pub share_stats: bool,
+	/// Record anonymous command usage and upload it in batches (opt-in, see `vasc telemetry`)
+	pub telemetry: bool,
+	/// Write logs to a file in the `.vasc/logs` directory
+	pub log_to_file: bool,
+	/// Maximum size of the log file in MiB before it gets rotated
+	pub log_max_size: u64,
+	/// Number of rotated log files to keep
+	pub log_max_files: u32,
+
+	#[serde(default)]
+	/// Per-module log levels, e.g. `server = "debug"`
+	pub log: HashMap<String, String>,
 
 	#[serde(skip)]
 	/// Internal
@@ -108,22 +159,37 @@ impl Default for Config {
 			port: 8000,
 			template: String::from("place"),
 			license: String::from("Apache-2.0"),
+			author: String::new(),
 			include_docs: false,
 			use_git: true,
+			include_examples: true,
 			use_wally: false,
 			use_selene: false,
 
 			run_async: false,
 			scan_ports: true,
+			request_log_level: String::from("trace"),
+			access_log: String::new(),
+			max_payload_size: 512,
+			rate_limit: 0,
+			allowed_ips: String::new(),
+			denied_ips: String::new(),
+			tls: false,
+			tls_cert: String::new(),
+			tls_key: String::new(),
 			detect_project: true,
 			smart_paths: false,
 			with_sourcemap: false,
 			build_xml: false,
+			asset_creator: String::new(),
+			api_key_file: String::new(),
 
 			check_updates: true,
 			auto_update: false,
 			install_plugin: true,
+			plugin_paths: String::new(),
 			update_templates: true,
+			update_channel: String::from("stable"),
 
 			rojo_mode: true,
 			ts_mode: false,
@@ -131,13 +197,23 @@ impl Default for Config {
 			rename_instances: true,
 			keep_duplicates: false,
 			move_to_bin: false,
+			format_on_sync: false,
+			lint_on_sync: false,
 			changes_threshold: 5,
 			max_unsynced_changes: 10,
 
 			lua_extension: false,
 			ignore_line_endings: true,
+			use_polling: false,
+			polling_interval: 1000,
 			package_manager: String::from("npm"),
 			share_stats: true,
+			telemetry: false,
+			log_to_file: false,
+			log_max_size: 10,
+			log_max_files: 5,
+
+			log: HashMap::new(),
 
 			kind: ConfigKind::default(),
 		}
@@ -178,7 +254,7 @@ impl Config {
 			};
 
 			if let Some(path) = kind.path() {
-				config.merge_opt(toml::from_str(&fs::read_to_string(path)?)?);
+				config.merge_opt(Self::read(path)?);
 			}
 
 			config.kind = kind.clone();
@@ -230,7 +306,7 @@ impl Config {
 		let mut config = Self::default();
 
 		let load_result = || -> Result<()> {
-			config.merge_opt(toml::from_str(&fs::read_to_string(path)?)?);
+			config.merge_opt(Self::read(path)?);
 
 			config.kind = match kind {
 				ConfigKind::Global(_) => ConfigKind::Global(path.to_owned()),
@@ -251,6 +327,15 @@ impl Config {
 		}
 	}
 
+	fn read(path: &Path) -> Result<OptConfig> {
+		let mut table: toml::Table = toml::from_str(&fs::read_to_string(path)?)?;
+
+		// Config files are not versioned so all migrations are always applied
+		migrations::upgrade(&mut table, migrations::CONFIG);
+
+		Ok(table.try_into()?)
+	}
+
 	pub fn save(&self, path: &Path) -> Result<()> {
 		fs::write(path, toml::to_string(self)?)?;
 
@@ -319,7 +404,7 @@ impl PartialEq for Config {
 			}
 		}
 
-		true
+		self.log == other.log
 	}
 }
 
@@ -339,6 +424,11 @@ impl Serialize for Config {
 			map.serialize_entry(&k, &v)?;
 		}
 
+		// Tables have to be serialized after all plain values
+		if !self.log.is_empty() {
+			map.serialize_entry("log", &self.log)?;
+		}
+
 		map.end()
 	}
 }
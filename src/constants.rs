@@ -10,18 +10,31 @@ pub const BLACKLISTED_PATHS: [&str; 1] = [".DS_Store"];
 // Current version of the project templates, this constant
 // should be manually bumped when there are any changes
 // made to the `assets/templates` directory
-pub const TEMPLATES_VERSION: u8 = 4;
-
-// Maximum payload size that can be sent from client
-// to the server, usually containing changes to apply,
-// currently it is 512 MiB but it is a huge overkill
-pub const MAX_PAYLOAD_SIZE: usize = 536_870_912;
+pub const TEMPLATES_VERSION: u8 = 6;
 
 /// How long the server should wait for the changes to
 /// appear in the queue before manually "timing out"
 /// the client request and sending back an empty `Changes`
 pub const QUEUE_TIMEOUT: Duration = Duration::from_secs(60);
 
+/// How long the server waits for the plugin to report
+/// the output of executed code before giving up
+pub const EXEC_TIMEOUT: Duration = Duration::from_secs(30);
+
+// Features supported by the server, reported to the
+// plugin in project details so it can adjust its UI
+pub const SERVER_CAPABILITIES: [&str; 10] = [
+	"read", "write", "snapshot", "exec", "output", "pull", "open", "health", "ws", "delta",
+];
+
+// Clients subscribing with at least this protocol version receive
+// delta frames: updates carry only changed and removed properties
+pub const DELTA_PROTOCOL_VERSION: u32 = 2;
+
+// Changes arriving within this time of each other are
+// batched into a single rebuild when building in watch mode
+pub const BUILD_DEBOUNCE_TIME: Duration = Duration::from_millis(300);
+
 // VFS events will be ignored for this amount of time
 // after the last change that has been made by the client,
 // this saves a lot of computing time
@@ -49,6 +49,12 @@ impl Changes {
 		self.additions.is_empty() && self.updates.is_empty() && self.removals.is_empty()
 	}
 
+	/// Convert updates into delta frames, see [`UpdatedSnapshot::into_delta`]
+	pub fn into_delta(mut self) -> Self {
+		self.updates = self.updates.into_iter().map(UpdatedSnapshot::into_delta).collect();
+		self
+	}
+
 	pub fn total(&self) -> usize {
 		self.additions.len() + self.updates.len() + self.removals.len()
 	}
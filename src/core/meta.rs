@@ -1,14 +1,18 @@
 use serde::{Deserialize, Serialize};
 use std::{
+	collections::HashMap,
 	fmt::Display,
 	path::{Path, PathBuf},
+	sync::Arc,
 };
 
 use crate::{
+	assets,
 	config::Config,
 	constants::default_sync_rules,
 	ext::PathExt,
 	glob::Glob,
+	integration,
 	middleware::Middleware,
 	project::{Project, ProjectNode},
 };
@@ -406,6 +410,8 @@ pub struct Context {
 	syncback_filter: SyncbackFilter,
 	/// Whether to use legacy script context
 	legacy_scripts: bool,
+	/// IDs of the uploaded assets keyed by their path
+	asset_ids: Arc<HashMap<String, u64>>,
 }
 
 impl Context {
@@ -415,6 +421,7 @@ impl Context {
 			ignore_rules: Vec::new(),
 			syncback_filter: SyncbackFilter::default(),
 			legacy_scripts: true,
+			asset_ids: Arc::new(HashMap::new()),
 		}
 	}
 
@@ -464,6 +471,14 @@ impl Context {
 	pub fn use_legacy_scripts(&self) -> bool {
 		self.legacy_scripts
 	}
+
+	pub fn asset_ids(&self) -> &HashMap<String, u64> {
+		&self.asset_ids
+	}
+
+	pub fn set_asset_ids(&mut self, asset_ids: Arc<HashMap<String, u64>>) {
+		self.asset_ids = asset_ids;
+	}
 }
 
 impl Default for Context {
@@ -504,7 +519,7 @@ impl Meta {
 	}
 
 	pub fn from_project(project: &Project) -> Self {
-		let syncback_filter = if let Some(syncback) = &project.syncback {
+		let mut syncback_filter = if let Some(syncback) = &project.syncback {
 			SyncbackFilter {
 				ignore_rules: IgnoreRule::from_globs(syncback.ignore_globs.clone(), project.workspace_dir.clone()),
 				ignore_names: syncback.ignore_names.clone(),
@@ -515,11 +530,18 @@ impl Meta {
 			SyncbackFilter::default()
 		};
 
+		// Packages are generated by Wally so they are never synced back
+		syncback_filter.ignore_rules.extend(IgnoreRule::from_globs(
+			integration::get_package_globs(&project.workspace_dir),
+			project.workspace_dir.clone(),
+		));
+
 		let context = Context {
 			sync_rules: project.sync_rules.clone(),
 			ignore_rules: IgnoreRule::from_globs(project.ignore_globs.clone(), project.workspace_dir.clone()),
 			syncback_filter,
 			legacy_scripts: project.legacy_scripts.unwrap_or(true),
+			asset_ids: Arc::new(assets::get_asset_ids(&project.workspace_dir)),
 		};
 
 		Self {
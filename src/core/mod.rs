@@ -16,7 +16,10 @@ use self::{
 	queue::Queue,
 	tree::Tree,
 };
-use crate::{core::snapshot::Snapshot, lock, middleware::new_snapshot, project::Project, stats, util, vfs::Vfs};
+use crate::{
+	assets, core::snapshot::Snapshot, lock, middleware::new_snapshot, project::Project, stats, telemetry, util,
+	vfs::Vfs,
+};
 
 pub mod changes;
 pub mod helpers;
@@ -43,6 +46,13 @@ impl Core {
 
 		let vfs = Vfs::new(watch);
 
+		// Uploading assets while serving rewrites the lockfile
+		let lockfile = project.workspace_dir.join(assets::LOCKFILE_NAME);
+
+		if vfs.is_file(&lockfile) {
+			vfs.watch(&lockfile, false)?;
+		}
+
 		trace!("Snapshotting root project");
 
 		let meta = Meta::from_project(&project);
@@ -56,7 +66,10 @@ impl Core {
 		trace!("Building Tree and Queue");
 
 		let vfs = Arc::new(vfs);
-		let tree = Arc::new(Mutex::new(Tree::new(snapshot)));
+		let tree = Tree::new(snapshot);
+		telemetry::set_project_size(tree.meta_map().len());
+
+		let tree = Arc::new(Mutex::new(tree));
 		let queue = Arc::new(Queue::new());
 
 		trace!("Starting Processor");
@@ -2,20 +2,25 @@ use anyhow::Result;
 use colored::Colorize;
 use crossbeam_channel::{select, Sender};
 use log::{debug, error, info, trace, warn};
+use rbx_dom_weak::types::Ref;
 use serde::Deserialize;
 use std::{
-	sync::{Arc, Mutex},
+	path::PathBuf,
+	sync::{
+		atomic::{AtomicBool, Ordering},
+		Arc, Mutex,
+	},
 	thread::Builder,
 };
 
 use super::{changes::Changes, queue::Queue, tree::Tree};
 use crate::{
-	argon_error,
+	argon_error, assets,
 	config::Config,
 	constants::BLACKLISTED_PATHS,
-	lock, logger,
+	integration, lock, logger,
 	project::{Project, ProjectDetails},
-	server, stats,
+	server, stats, t, vasc_warn,
 	vfs::{Vfs, VfsEvent},
 };
 
@@ -35,13 +40,19 @@ pub struct Processor {
 
 impl Processor {
 	pub fn new(queue: Arc<Queue>, tree: Arc<Mutex<Tree>>, vfs: Arc<Vfs>, project: Arc<Mutex<Project>>) -> Self {
+		let config = Config::new();
+
 		let handler = Arc::new(Handler {
 			queue,
 			tree,
 			vfs: vfs.clone(),
 			project,
+			format: AtomicBool::new(config.format_on_sync),
+			lint: AtomicBool::new(config.lint_on_sync),
 		});
 
+		drop(config);
+
 		let handler = handler.clone();
 		let (sender, receiver) = crossbeam_channel::unbounded();
 
@@ -58,8 +69,17 @@ impl Processor {
 						}
 						recv(client_receiver) -> request => {
 							vfs.pause();
-							handler.on_client_event(request?);
+							let scripts = handler.on_client_event(request?);
+							let formatted = handler.format(&scripts);
 							vfs.resume();
+
+							// Events of formatted files were dropped while VFS was paused,
+							// so they are read back here to update the tree and the client
+							for path in formatted {
+								handler.on_vfs_event(VfsEvent::Write(path));
+							}
+
+							handler.lint(&scripts);
 						}
 					}
 				}
@@ -79,6 +99,10 @@ struct Handler {
 	tree: Arc<Mutex<Tree>>,
 	vfs: Arc<Vfs>,
 	project: Arc<Mutex<Project>>,
+	/// Whether to format synced back scripts with StyLua
+	format: AtomicBool,
+	/// Whether to lint synced back scripts with selene
+	lint: AtomicBool,
 }
 
 impl Handler {
@@ -91,12 +115,29 @@ impl Handler {
 		let mut tree = lock!(self.tree);
 		let path = event.path();
 
-		let changes = {
-			if BLACKLISTED_PATHS.iter().any(|blacklisted| path.ends_with(blacklisted)) {
-				trace!("Processing of {path:?} aborted: blacklisted");
-				return;
+		if BLACKLISTED_PATHS.iter().any(|blacklisted| path.ends_with(blacklisted)) {
+			trace!("Processing of {path:?} aborted: blacklisted");
+			return;
+		}
+
+		let workspace_dir = lock!(self.project).workspace_dir.clone();
+
+		let changes = if path == workspace_dir.join(assets::LOCKFILE_NAME) {
+			debug!("Asset lockfile was modified. Reloading asset IDs..");
+
+			let asset_ids = Arc::new(assets::get_asset_ids(&workspace_dir));
+			let ids: Vec<Ref> = tree.meta_map().keys().copied().collect();
+
+			for id in ids {
+				if let Some(meta) = tree.get_meta_mut(id) {
+					meta.context.set_asset_ids(asset_ids.clone());
+				}
 			}
 
+			// Re-snapshot the whole project so the new IDs get substituted
+			let root = tree.root_ref();
+			read::process_changes(root, &mut tree, &self.vfs).unwrap_or_else(Changes::new)
+		} else {
 			let ids = {
 				let mut current_path = path;
 
@@ -172,8 +213,9 @@ impl Handler {
 		}
 	}
 
+	/// Applies client changes and returns paths of the written scripts
 	#[profiling::function]
-	fn on_client_event(&self, request: WriteRequest) {
+	fn on_client_event(&self, request: WriteRequest) -> Vec<PathBuf> {
 		profiling::start_frame!();
 
 		let changes = request.changes;
@@ -183,11 +225,11 @@ impl Handler {
 
 		if changes.total() > Config::new().changes_threshold {
 			let accept = logger::prompt(
-				&format!(
-					"You are about to apply {}, {} and {}. Do you want to continue?",
-					format!("{} additions", changes.additions.len()).bold().green(),
-					format!("{} updates", changes.updates.len()).bold().blue(),
-					format!("{} removals", changes.removals.len()).bold().red(),
+				&t!(
+					"sync.confirm_changes",
+					additions = t!("sync.additions", count = changes.additions.len()).bold().green(),
+					updates = t!("sync.updates", count = changes.updates.len()).bold().blue(),
+					removals = t!("sync.removals", count = changes.removals.len()).bold().red(),
 				),
 				true,
 			);
@@ -203,12 +245,19 @@ impl Handler {
 					Err(err) => warn!("Failed to disconnect client: {err}"),
 				}
 
-				return;
+				return Vec::new();
 			}
 		}
 
 		let mut tree = lock!(self.tree);
 
+		let ids = changes
+			.additions
+			.iter()
+			.map(|snapshot| snapshot.id)
+			.chain(changes.updates.iter().map(|snapshot| snapshot.id))
+			.collect::<Vec<_>>();
+
 		let result = || -> Result<()> {
 			for snapshot in changes.additions {
 				write::apply_addition(snapshot, &mut tree, &self.vfs)?;
@@ -231,5 +280,79 @@ impl Handler {
 		}
 
 		self.queue.push(server::SyncbackChanges(), Some(0)).ok();
+
+		if !self.format.load(Ordering::Relaxed) && !self.lint.load(Ordering::Relaxed) {
+			return Vec::new();
+		}
+
+		let mut scripts = Vec::new();
+
+		for id in ids {
+			collect_scripts(id, &tree, &mut scripts);
+		}
+
+		scripts.sort();
+		scripts.dedup();
+
+		scripts
+	}
+
+	/// Runs StyLua on scripts written by the client and returns them if they
+	/// were formatted, hook gets disabled if StyLua is not installed
+	fn format(&self, scripts: &[PathBuf]) -> Vec<PathBuf> {
+		if scripts.is_empty() || !self.format.load(Ordering::Relaxed) {
+			return Vec::new();
+		}
+
+		let workspace_dir = lock!(self.project).workspace_dir.clone();
+
+		match integration::format_files(&workspace_dir, scripts) {
+			Ok(true) => {
+				debug!("Formatted {} synced scripts", scripts.len());
+				return scripts.to_vec();
+			}
+			Ok(false) => self.format.store(false, Ordering::Relaxed),
+			Err(err) => vasc_warn!("Failed to format synced scripts: {}", err),
+		}
+
+		Vec::new()
+	}
+
+	/// Runs selene on scripts written by the client,
+	/// hook gets disabled if selene is not installed
+	fn lint(&self, scripts: &[PathBuf]) {
+		if scripts.is_empty() || !self.lint.load(Ordering::Relaxed) {
+			return;
+		}
+
+		let workspace_dir = lock!(self.project).workspace_dir.clone();
+
+		match integration::lint_files(&workspace_dir, scripts) {
+			Ok(Some(diagnostics)) if !diagnostics.is_empty() => {
+				vasc_warn!("Synced scripts have lint issues:\n{}", diagnostics)
+			}
+			Ok(Some(_)) => debug!("No lint issues found in {} synced scripts", scripts.len()),
+			Ok(None) => self.lint.store(false, Ordering::Relaxed),
+			Err(err) => vasc_warn!("Failed to lint synced scripts: {}", err),
+		}
+	}
+}
+
+fn collect_scripts(id: Ref, tree: &Tree, scripts: &mut Vec<PathBuf>) {
+	if let Some(file) = tree.get_meta(id).and_then(|meta| meta.source.get_file()) {
+		let path = file.path();
+
+		if path
+			.extension()
+			.is_some_and(|extension| extension == "lua" || extension == "luau")
+		{
+			scripts.push(path.to_owned());
+		}
+	}
+
+	if let Some(instance) = tree.get_instance(id) {
+		for child in instance.children() {
+			collect_scripts(*child, tree, scripts);
+		}
 	}
 }
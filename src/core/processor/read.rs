@@ -7,7 +7,7 @@ use crate::{
 	core::{
 		changes::Changes,
 		meta::SourceKind,
-		snapshot::{Snapshot, UpdatedSnapshot},
+		snapshot::{PropertiesDelta, Snapshot, UpdatedSnapshot},
 		tree::Tree,
 	},
 	middleware::{new_snapshot, project::new_snapshot_node},
@@ -93,6 +93,7 @@ fn process_child_changes(id: Ref, mut snapshot: Snapshot, changes: &mut Changes,
 	};
 
 	updated_snapshot.properties = if snapshot.properties != instance.properties {
+		updated_snapshot.delta = Some(PropertiesDelta::new(&instance.properties, &snapshot.properties));
 		instance.properties.clone_from(&snapshot.properties);
 		Some(snapshot.properties)
 	} else {
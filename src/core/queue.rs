@@ -1,11 +1,15 @@
 use anyhow::{bail, Result};
 use colored::Colorize;
 use crossbeam_channel::{Receiver, Sender};
-use std::{collections::HashMap, sync::RwLock};
+use std::{
+	collections::HashMap,
+	sync::RwLock,
+	time::{Duration, Instant},
+};
 
 use crate::{
 	config::Config,
-	constants::QUEUE_TIMEOUT,
+	constants::{DELTA_PROTOCOL_VERSION, QUEUE_TIMEOUT},
 	server::{self, Message},
 	vasc_warn,
 };
@@ -27,6 +31,7 @@ struct Listener {
 	pub id: u32,
 	pub name: String,
 	pub is_internal: bool,
+	pub protocol: u32,
 }
 
 #[derive(Debug)]
@@ -69,13 +74,21 @@ impl Queue {
 		}
 
 		let message: Message = message.into();
+		let mut delta = None;
 		let mut did_push = false;
 
 		for listener in read!(self.listeners).iter() {
 			let queues = read!(self.queues);
 			let sender = queues.get(&listener.id).unwrap().sender.clone();
 
-			sender.send(message.clone())?;
+			match &message {
+				Message::SyncChanges(server::SyncChanges(changes)) if listener.protocol >= DELTA_PROTOCOL_VERSION => {
+					let delta =
+						delta.get_or_insert_with(|| Message::from(server::SyncChanges(changes.clone().into_delta())));
+					sender.send(delta.clone())?;
+				}
+				_ => sender.send(message.clone())?,
+			}
 
 			did_push = true;
 		}
@@ -142,7 +155,38 @@ impl Queue {
 		})
 	}
 
-	pub fn subscribe(&self, id: u32, name: &str) -> Result<()> {
+	/// Receive all changes that keep arriving within the given window of each
+	/// other, but for no longer than 10 windows in total so a steady stream of
+	/// changes can't postpone processing forever, returns the number of changes
+	pub fn drain_changes(&self, id: u32, window: Duration) -> Result<usize> {
+		if !self.is_subscribed(id) {
+			bail!("Not subscribed")
+		}
+
+		let queues = read!(self.queues);
+		let receiver = queues.get(&id).unwrap().receiver.clone();
+
+		drop(queues);
+
+		let started = Instant::now();
+		let mut count = 0;
+
+		while started.elapsed() < window * 10 {
+			let Ok(message) = receiver.recv_timeout(window) else {
+				break;
+			};
+
+			if message.is_change() {
+				count += 1;
+			}
+		}
+
+		Ok(count)
+	}
+
+	/// Subscribe client, `protocol` is the version of the protocol
+	/// it supports and determines how messages are encoded for it
+	pub fn subscribe(&self, id: u32, name: &str, protocol: u32) -> Result<()> {
 		if self.is_subscribed(id) {
 			bail!("Already subscribed")
 		}
@@ -154,6 +198,7 @@ impl Queue {
 			id,
 			name: name.to_owned(),
 			is_internal: false,
+			protocol,
 		};
 
 		write!(self.listeners).push(listener);
@@ -180,6 +225,7 @@ impl Queue {
 			id,
 			name: format!("Internal listener #{id}"),
 			is_internal: true,
+			protocol: 0,
 		};
 
 		write!(self.listeners).push(listener);
@@ -218,6 +264,13 @@ impl Queue {
 		read!(self.listeners).iter().any(|listener| listener.id == id)
 	}
 
+	pub fn client_count(&self) -> usize {
+		read!(self.listeners)
+			.iter()
+			.filter(|listener| !listener.is_internal)
+			.count()
+	}
+
 	pub fn get_first_non_internal_listener_name(&self) -> Option<String> {
 		read!(self.listeners)
 			.iter()
@@ -212,6 +212,27 @@ impl From<AddedSnapshot> for Snapshot {
 	}
 }
 
+/// Names of the properties that changed since the previous state of the instance
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PropertiesDelta {
+	pub changed: Vec<Ustr>,
+	pub removed: Vec<Ustr>,
+}
+
+impl PropertiesDelta {
+	pub fn new(old: &Properties, new: &Properties) -> Self {
+		let changed = new
+			.iter()
+			.filter(|(name, value)| old.get(*name) != Some(*value))
+			.map(|(name, _)| *name)
+			.collect();
+
+		let removed = old.keys().filter(|name| !new.contains_key(*name)).copied().collect();
+
+		Self { changed, removed }
+	}
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UpdatedSnapshot {
 	pub id: Ref,
@@ -219,6 +240,15 @@ pub struct UpdatedSnapshot {
 	pub name: Option<String>,
 	pub class: Option<Ustr>,
 	pub properties: Option<Properties>,
+	/// Only present in delta frames, `properties` then contain changed properties only
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub removed_properties: Vec<Ustr>,
+	/// Whether `properties` only contain changed properties,
+	/// otherwise they replace all properties of the instance
+	#[serde(default)]
+	pub is_delta: bool,
+	#[serde(skip)]
+	pub delta: Option<PropertiesDelta>,
 }
 
 impl UpdatedSnapshot {
@@ -229,10 +259,25 @@ impl UpdatedSnapshot {
 			class: None,
 			properties: None,
 			meta: None,
+			removed_properties: Vec::new(),
+			is_delta: false,
+			delta: None,
 		}
 	}
 
 	pub fn is_empty(&self) -> bool {
 		self.meta.is_none() && self.name.is_none() && self.class.is_none() && self.properties.is_none()
 	}
+
+	/// Strip properties that did not change, so only the delta is sent,
+	/// updates without a computed delta are left as full updates
+	pub fn into_delta(mut self) -> Self {
+		if let (Some(properties), Some(delta)) = (&mut self.properties, self.delta.take()) {
+			properties.retain(|name, _| delta.changed.contains(name));
+			self.removed_properties = delta.removed;
+			self.is_delta = true;
+		}
+
+		self
+	}
 }
@@ -1,11 +1,21 @@
 use backtrace::Backtrace;
+use chrono::Local;
 use colored::Colorize;
 use log::{error, trace, LevelFilter};
 use open;
 use panic_message::get_panic_info_message;
-use std::{env, panic, process};
-
-use crate::{logger, util, vasc_error};
+use std::{
+	env::{
+		self,
+		consts::{ARCH, OS},
+	},
+	fs,
+	panic::{self, Location},
+	path::PathBuf,
+	process,
+};
+
+use crate::{ext::PathExt, logger, util, vasc_error};
 
 const MAX_BACKTRACE_LEN: usize = 6500;
 
@@ -85,6 +95,16 @@ pub fn hook() {
 			);
 		}
 
+		match save_report(message, panic_info.location()) {
+			Ok(path) => vasc_error!(
+				"{}: Crash report saved to {}, attach it when reporting the issue at {}",
+				"Report".bold(),
+				path.to_string().bold(),
+				format!("{}/issues", env!("CARGO_PKG_REPOSITORY")).bold()
+			),
+			Err(err) => error!("Failed to save crash report: {err}"),
+		}
+
 		let report_issue = logger::prompt(
 			"Would you like to create new issue on GitHub with current report?",
 			false,
@@ -109,3 +129,55 @@ pub fn hook() {
 		process::exit(1)
 	}));
 }
+
+/// Returns the subcommand and option names Vasc was run with, argument
+/// values are left out as they can contain secrets like API keys or tokens
+fn get_command() -> String {
+	let mut command = vec![String::from("vasc")];
+	let mut subcommand = false;
+
+	for arg in env::args().skip(1) {
+		if arg.starts_with('-') {
+			command.push(arg.split('=').next().unwrap_or_default().to_owned());
+		} else if !subcommand {
+			command.push(arg);
+			subcommand = true;
+		}
+	}
+
+	command.join(" ")
+}
+
+/// Writes full crash report to the `.vasc/crashes` directory
+fn save_report(message: &str, location: Option<&Location>) -> anyhow::Result<PathBuf> {
+	let dir = util::get_vasc_dir()?.join("crashes");
+	fs::create_dir_all(&dir)?;
+
+	let path = dir.join(format!("crash-{}.md", Local::now().format("%Y%m%d-%H%M%S")));
+
+	let location = location
+		.map(|location| format!("{}: {}", location.file(), location.line()))
+		.unwrap_or("Unknown".into());
+
+	let mut report = format!("# Vasc crash report\n\nVersion: {}\n", env!("CARGO_PKG_VERSION"));
+
+	report.push_str(&format!("Platform: {OS} {ARCH}\n"));
+	report.push_str(&format!("Command: {}\n", get_command()));
+	report.push_str(&format!("Message: {message}\n"));
+	report.push_str(&format!("Location: {location}\n"));
+
+	report.push_str("\n## Backtrace\n\n```\n");
+	report.push_str(&format!("{:?}", Backtrace::new()));
+	report.push_str("```\n\n## Recent logs\n\n```\n");
+
+	for line in logger::recent_logs() {
+		report.push_str(&line);
+		report.push('\n');
+	}
+
+	report.push_str("```\n");
+
+	fs::write(&path, report)?;
+
+	Ok(path)
+}
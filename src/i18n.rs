@@ -0,0 +1,127 @@
+use lazy_static::lazy_static;
+use log::{debug, warn};
+use std::{collections::HashMap, env};
+use toml::{Table, Value};
+
+/// Environment variable that overrides the system locale
+pub const LANG_VAR: &str = "VASC_LANG";
+
+const DEFAULT_LOCALE: &str = "en";
+const LOCALES: [(&str, &str); 2] = [
+	("en", include_str!("../assets/locales/en.toml")),
+	("pt", include_str!("../assets/locales/pt.toml")),
+];
+
+lazy_static! {
+	static ref CATALOG: Catalog = Catalog::new(&get_locale());
+}
+
+/// Translate message and replace its `{name}` placeholders,
+/// e.g. `t!("updater.cli_failed", error = err)`
+#[macro_export]
+macro_rules! t {
+	($key:expr) => {
+		$crate::i18n::translate($key, &[])
+	};
+	($key:expr, $($name:ident = $value:expr),+ $(,)?) => {
+		$crate::i18n::translate($key, &[$((stringify!($name), $value.to_string())),+])
+	};
+}
+
+struct Catalog {
+	messages: HashMap<String, String>,
+	fallback: HashMap<String, String>,
+}
+
+impl Catalog {
+	fn new(locale: &str) -> Self {
+		let fallback = load(DEFAULT_LOCALE);
+
+		let messages = if locale == DEFAULT_LOCALE {
+			HashMap::new()
+		} else {
+			load(locale)
+		};
+
+		Self { messages, fallback }
+	}
+
+	fn get(&self, key: &str) -> Option<&String> {
+		self.messages.get(key).or_else(|| self.fallback.get(key))
+	}
+}
+
+fn load(locale: &str) -> HashMap<String, String> {
+	let Some((_, source)) = LOCALES.iter().find(|(name, _)| *name == locale) else {
+		debug!("No messages for locale: {locale}, falling back to {DEFAULT_LOCALE}");
+		return HashMap::new();
+	};
+
+	let mut messages = HashMap::new();
+
+	match toml::from_str::<Table>(source) {
+		Ok(table) => flatten(&table, "", &mut messages),
+		Err(err) => warn!("Failed to parse messages of locale {locale}: {err}"),
+	}
+
+	messages
+}
+
+fn flatten(table: &Table, prefix: &str, messages: &mut HashMap<String, String>) {
+	for (key, value) in table {
+		let key = if prefix.is_empty() {
+			key.to_owned()
+		} else {
+			format!("{prefix}.{key}")
+		};
+
+		match value {
+			Value::String(message) => {
+				messages.insert(key, message.to_owned());
+			}
+			Value::Table(table) => flatten(table, &key, messages),
+			_ => (),
+		}
+	}
+}
+
+/// Returns the language code of the user, taken from `VASC_LANG`
+/// or the standard locale variables (`pt_BR.UTF-8` -> `pt`)
+pub fn get_locale() -> String {
+	let locale = [LANG_VAR, "LC_ALL", "LC_MESSAGES", "LANG"]
+		.iter()
+		.filter_map(|var| env::var(var).ok())
+		.find(|locale| !locale.is_empty());
+
+	let Some(locale) = locale else {
+		return DEFAULT_LOCALE.to_owned();
+	};
+
+	let language = locale
+		.split(['_', '-', '.', '@'])
+		.next()
+		.unwrap_or_default()
+		.to_lowercase();
+
+	match language.as_str() {
+		"" | "c" | "posix" => DEFAULT_LOCALE.to_owned(),
+		_ => language,
+	}
+}
+
+/// Returns message of the current locale, English one if it's
+/// not translated or the key itself if it does not exist at all
+pub fn translate(key: &str, args: &[(&str, String)]) -> String {
+	let Some(message) = CATALOG.get(key) else {
+		debug!("Missing message: {key}");
+		return key.to_owned();
+	};
+
+	let mut message = message.to_owned();
+
+	for (name, value) in args {
+		message = message.replace(&format!("{{{name}}}"), value);
+	}
+
+	message
+}
@@ -3,14 +3,24 @@ use colored::Colorize;
 use include_dir::{include_dir, Dir};
 use log::trace;
 use rbx_dom_weak::{types::Variant, ustr};
-use self_update::{backends::github::Update, self_replace, update::UpdateStatus};
-use std::{env, fs, path::Path};
+use self_update::{
+	backends::github::Update,
+	self_replace,
+	update::{Release, UpdateStatus},
+};
+use std::{
+	env, fs,
+	path::{Path, PathBuf},
+};
 
 use crate::{
+	constants::TEMPLATES_VERSION,
 	ext::PathExt,
-	logger, updater,
-	util::{self, get_plugin_path},
-	vasc_error, vasc_info,
+	logger, sessions,
+	templates::{self, TemplateManifest},
+	updater,
+	util::{self, get_plugin_paths},
+	vasc_error, vasc_info, vasc_warn,
 };
 
 const PLACE_TEMPLATE: Dir = include_dir!("$CARGO_MANIFEST_DIR/assets/templates/place");
@@ -19,6 +29,10 @@ const PACKAGE_TEMPLATE: Dir = include_dir!("$CARGO_MANIFEST_DIR/assets/templates
 const MODEL_TEMPLATE: Dir = include_dir!("$CARGO_MANIFEST_DIR/assets/templates/model");
 const QUICK_TEMPLATE: Dir = include_dir!("$CARGO_MANIFEST_DIR/assets/templates/quick");
 const EMPTY_TEMPLATE: Dir = include_dir!("$CARGO_MANIFEST_DIR/assets/templates/empty");
+const WORKSPACE_TEMPLATE: Dir = include_dir!("$CARGO_MANIFEST_DIR/assets/templates/workspace");
+
+/// Beta plugin is installed next to the stable one under this name
+pub const BETA_PLUGIN_NAME: &str = "VascBeta.rbxm";
 
 const VASC_PLUGIN: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/Vasc.rbxm"));
 
@@ -31,7 +45,7 @@ pub fn is_managed() -> bool {
 	!path.contains(&[".vasc", "bin"]) && (path.contains(&["bin"]) || path.contains(&["tool-storage"]))
 }
 
-pub fn verify(is_managed: bool, with_plugin: bool) -> Result<()> {
+pub fn verify(is_managed: bool, with_plugin: bool, with_beta: bool) -> Result<()> {
 	if !is_managed {
 		let bin_dir = util::get_vasc_dir()?.join("bin");
 
@@ -59,10 +73,16 @@ pub fn verify(is_managed: bool, with_plugin: bool) -> Result<()> {
 	install_templates(false)?;
 
 	if with_plugin {
-		let plugin_path = get_plugin_path()?;
+		for plugin_path in get_plugin_paths()? {
+			if !plugin_path.exists() {
+				install_plugin(&plugin_path, false)?;
+			}
+
+			let beta_path = get_beta_path(&plugin_path);
 
-		if !plugin_path.exists() {
-			install_plugin(&plugin_path, false)?;
+			if with_beta && !beta_path.exists() {
+				install_beta_plugin(&beta_path, false)?;
+			}
 		}
 	}
 
@@ -117,44 +137,91 @@ pub fn install_plugin(path: &Path, show_progress: bool) -> Result<()> {
 }
 
 pub fn install_templates(update: bool) -> Result<()> {
-	let templates_dir = util::get_vasc_dir()?.join("templates");
-
-	let place_template = templates_dir.join("place");
-	let plugin_template = templates_dir.join("plugin");
-	let package_template = templates_dir.join("package");
-	let model_template = templates_dir.join("model");
-	let quick_template = templates_dir.join("quick");
-	let empty_template = templates_dir.join("empty");
-
-	if update || !place_template.exists() {
-		fs::create_dir_all(&place_template)?;
-		install_template(&PLACE_TEMPLATE, &place_template)?;
+	let templates_dir = templates::get_templates_dir()?;
+
+	let place_examples: &[&str] = &[
+		"src/Client/Main.client.luau",
+		"src/Server/Main.server.luau",
+		"src/Shared/Hello.luau",
+	];
+
+	let workspace_examples: &[&str] = &[
+		"src/Game/Client/Main.client.luau",
+		"src/Game/Server/Main.server.luau",
+		"src/Lobby/Client/Main.client.luau",
+		"src/Lobby/Server/Main.server.luau",
+		"src/Shared/Hello.luau",
+	];
+
+	let no_examples: &[&str] = &[];
+
+	let bundled = [
+		(&PLACE_TEMPLATE, "place", "Full game with all services", place_examples),
+		(&PLUGIN_TEMPLATE, "plugin", "Roblox Studio plugin", no_examples),
+		(&PACKAGE_TEMPLATE, "package", "Wally package", no_examples),
+		(&MODEL_TEMPLATE, "model", "Single model", no_examples),
+		(&QUICK_TEMPLATE, "quick", "Minimal place with scripts only", no_examples),
+		(&EMPTY_TEMPLATE, "empty", "Empty project", no_examples),
+		(
+			&WORKSPACE_TEMPLATE,
+			"workspace",
+			"Multiple places sharing common code",
+			workspace_examples,
+		),
+	];
+
+	for (template, name, description, examples) in bundled {
+		let path = templates_dir.join(name);
+
+		if update || !path.exists() {
+			fs::create_dir_all(&path)?;
+			install_template(template, &path)?;
+
+			let manifest = TemplateManifest::new(name, description, &TEMPLATES_VERSION.to_string(), "bundled")
+				.with_examples(examples);
+			templates::set_manifest(&path, &manifest)?;
+		}
 	}
 
-	if update || !plugin_template.exists() {
-		fs::create_dir_all(&plugin_template)?;
-		install_template(&PLUGIN_TEMPLATE, &plugin_template)?;
-	}
+	Ok(())
+}
 
-	if update || !package_template.exists() {
-		fs::create_dir_all(&package_template)?;
-		install_template(&PACKAGE_TEMPLATE, &package_template)?;
-	}
+pub fn get_beta_path(path: &Path) -> PathBuf {
+	path.with_file_name(BETA_PLUGIN_NAME)
+}
 
-	if update || !model_template.exists() {
-		fs::create_dir_all(&model_template)?;
-		install_template(&MODEL_TEMPLATE, &model_template)?;
-	}
+pub fn install_beta_plugin(path: &Path, show_progress: bool) -> Result<()> {
+	let Some(release) = updater::get_latest_beta_release()? else {
+		vasc_warn!("There is no beta release of Vasc plugin available");
+		return Ok(());
+	};
 
-	if update || !quick_template.exists() {
-		fs::create_dir_all(&quick_template)?;
-		install_template(&QUICK_TEMPLATE, &quick_template)?;
-	}
+	download_beta_plugin(&release, path, show_progress)?;
 
-	if update || !empty_template.exists() {
-		fs::create_dir_all(&empty_template)?;
-		install_template(&EMPTY_TEMPLATE, &empty_template)?;
-	}
+	vasc_info!("Installed Vasc beta plugin, version: {}", release.version.bold());
+
+	let mut status = updater::get_status()?;
+	status.beta_plugin_version = Some(release.version);
+
+	updater::set_status(&status)
+}
+
+pub fn download_beta_plugin(release: &Release, path: &Path, show_progress: bool) -> Result<()> {
+	fs::create_dir_all(path.get_parent())?;
+
+	let style = util::get_progress_style();
+
+	Update::configure()
+		.repo_owner("vadymcap")
+		.repo_name("Vasc-roblox")
+		.bin_name("Vasc.rbxm")
+		.target("")
+		.target_version_tag(&release.version)
+		.show_download_progress(show_progress)
+		.set_progress_style(style.0, style.1)
+		.bin_install_path(path)
+		.build()?
+		.download()?;
 
 	Ok(())
 }
@@ -174,6 +241,44 @@ fn install_template(template: &Dir, path: &Path) -> Result<()> {
 	Ok(())
 }
 
+/// Removes installed plugins, templates and cached state files while
+/// preserving user config, returns paths that were actually deleted
+/// together with the number of pruned stale sessions
+pub fn clean() -> Result<(Vec<PathBuf>, usize)> {
+	let vasc_dir = util::get_vasc_dir()?;
+
+	let mut paths = vec![];
+
+	for path in get_plugin_paths().unwrap_or_default() {
+		paths.push(get_beta_path(&path));
+		paths.push(path);
+	}
+
+	paths.push(templates::get_templates_dir()?);
+	paths.push(vasc_dir.join("template-sources"));
+	paths.push(vasc_dir.join("update.toml"));
+
+	// Running sessions have to stay registered, so only dead ones are pruned
+	let stale = sessions::remove_stale()?;
+
+	let mut removed = vec![];
+
+	for path in paths {
+		if path.is_dir() {
+			fs::remove_dir_all(&path)?;
+		} else if path.is_file() {
+			fs::remove_file(&path)?;
+		} else {
+			continue;
+		}
+
+		trace!("Removed: {}", path.display());
+		removed.push(path);
+	}
+
+	Ok((removed, stale))
+}
+
 pub fn get_plugin_version() -> String {
 	// May seem hacky, but this function will only be
 	// called once for most users and is non-critical anyway
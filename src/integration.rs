@@ -1,13 +1,20 @@
-use anyhow::Result;
+use anyhow::{bail, Result};
 use colored::Colorize;
 use log::{debug, warn};
 use serde::Deserialize;
-use std::{collections::HashMap, fs, path::Path};
+use std::{
+	collections::HashMap,
+	fs,
+	path::{Path, PathBuf},
+	process::Output,
+};
 
 use crate::{
+	ext::PathExt,
 	glob::Glob,
 	logger,
 	program::{Program, ProgramName},
+	util,
 };
 
 type Deps = HashMap<String, String>;
@@ -32,14 +39,126 @@ impl WallyManifest {
 			}
 		};
 
-		join(&self.dependencies, "Packages");
-		join(&self.server_dependencies, "ServerPackages");
-		join(&self.dev_dependencies, "DevPackages");
+		join(&self.dependencies, PACKAGE_DIRS[0]);
+		join(&self.server_dependencies, PACKAGE_DIRS[1]);
+		join(&self.dev_dependencies, PACKAGE_DIRS[2]);
 
 		directories
 	}
 }
 
+/// Directories that Wally installs packages into
+pub const PACKAGE_DIRS: [&str; 3] = ["Packages", "ServerPackages", "DevPackages"];
+
+const TYPE_DEFINITIONS_NAME: &str = "globalTypes.d.luau";
+const TYPE_DEFINITIONS_URL: &str =
+	"https://raw.githubusercontent.com/JohnnyMorganz/luau-lsp/main/scripts/globalTypes.d.luau";
+
+/// Returns globs matching generated package directories,
+/// empty if the workspace does not use Wally
+pub fn get_package_globs(workspace_path: &Path) -> Vec<Glob> {
+	if !workspace_path.join("wally.toml").exists() {
+		return Vec::new();
+	}
+
+	PACKAGE_DIRS
+		.iter()
+		.filter_map(|dir| Glob::new(&format!("{dir}/**")).ok())
+		.collect()
+}
+
+/// Run `wally install` in the given workspace
+pub fn install_packages(workspace_path: &Path) -> Result<bool> {
+	let output = Program::new(ProgramName::Wally)
+		.message("Failed to install dependencies")
+		.arg("install")
+		.current_dir(workspace_path)
+		.output()?;
+
+	let Some(output) = output else {
+		return Ok(false);
+	};
+
+	if !output.status.success() {
+		bail!(
+			"Failed to install dependencies: {}",
+			String::from_utf8_lossy(&output.stderr).trim()
+		);
+	}
+
+	Ok(true)
+}
+
+/// Format files with StyLua, returns `false` if StyLua is not installed
+pub fn format_files(workspace_path: &Path, paths: &[PathBuf]) -> Result<bool> {
+	let output = Program::new(ProgramName::StyLua)
+		.message("Failed to format synced scripts")
+		.current_dir(workspace_path)
+		.args(paths.iter().map(|path| path.to_string()))
+		.output()?;
+
+	let Some(output) = output else {
+		return Ok(false);
+	};
+
+	if !output.status.success() {
+		bail!("{}", String::from_utf8_lossy(&output.stderr).trim());
+	}
+
+	Ok(true)
+}
+
+/// Lint files with selene and return reported diagnostics,
+/// returns `None` if selene is not installed
+pub fn lint_files(workspace_path: &Path, paths: &[PathBuf]) -> Result<Option<String>> {
+	let output = Program::new(ProgramName::Selene)
+		.message("Failed to lint synced scripts")
+		.current_dir(workspace_path)
+		.arg("--display-style=quiet")
+		.args(paths.iter().map(|path| path.to_string()))
+		.output()?;
+
+	Ok(output.map(|output| String::from_utf8_lossy(&output.stdout).trim().to_owned()))
+}
+
+/// Returns path to the Roblox type definitions, downloads them
+/// if they are missing or `update` is set
+pub fn get_type_definitions(update: bool) -> Result<PathBuf> {
+	let path = util::get_vasc_dir()?.join(TYPE_DEFINITIONS_NAME);
+
+	if path.exists() && !update {
+		return Ok(path);
+	}
+
+	debug!("Downloading Roblox type definitions..");
+
+	let definitions = reqwest::blocking::get(TYPE_DEFINITIONS_URL)?
+		.error_for_status()?
+		.text()?;
+	fs::write(&path, definitions)?;
+
+	Ok(path)
+}
+
+/// Type check the workspace with luau-lsp, returns `None` if luau-lsp is not installed
+pub fn analyze(workspace_path: &Path, sourcemap: &Path, definitions: &Path) -> Result<Option<Output>> {
+	let mut program = Program::new(ProgramName::LuauLsp);
+
+	program
+		.message("Failed to analyze project")
+		.current_dir(workspace_path)
+		.arg("analyze")
+		.arg(format!("--sourcemap={}", sourcemap.to_string()))
+		.arg(format!("--definitions={}", definitions.to_string()))
+		.arg("--formatter=plain");
+
+	for dir in PACKAGE_DIRS {
+		program.arg(format!("--ignore={dir}/**"));
+	}
+
+	program.arg(".").output()
+}
+
 fn install_wally_packages(workspace_path: &Path) -> Result<()> {
 	let install = logger::prompt(
         &format!("Looks like your project uses Wally but one of the directories is missing or one of the dependencies is not installed. Would you like to run {} now?",
@@ -52,11 +171,7 @@ fn install_wally_packages(workspace_path: &Path) -> Result<()> {
 		return Ok(());
 	}
 
-	Program::new(ProgramName::Wally)
-		.message("Failed to install dependencies")
-		.arg("install")
-		.current_dir(workspace_path)
-		.output()?;
+	install_packages(workspace_path)?;
 
 	Ok(())
 }
@@ -2,6 +2,7 @@
 
 use rbx_dom_weak::{types::Variant, UstrMap};
 
+pub mod assets;
 pub mod cli;
 pub mod config;
 pub mod constants;
@@ -9,10 +10,14 @@ pub mod core;
 pub mod crash_handler;
 pub mod ext;
 pub mod glob;
+pub mod i18n;
 pub mod installer;
 pub mod integration;
 pub mod logger;
 pub mod middleware;
+pub mod migrations;
+pub mod open_cloud;
+pub mod processes;
 pub mod program;
 pub mod project;
 pub mod resolution;
@@ -20,9 +25,12 @@ pub mod server;
 pub mod sessions;
 pub mod stats;
 pub mod studio;
+pub mod telemetry;
+pub mod templates;
 pub mod updater;
 pub mod util;
 pub mod vfs;
+pub mod wizard;
 pub mod workspace;
 
 /// Global type for snapshot and instance properties
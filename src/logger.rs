@@ -1,13 +1,27 @@
+use chrono::{DateTime, Local};
+use clap::ValueEnum;
 use colored::{Color, Colorize};
 use dialoguer::console::{style, Style, StyledObject};
 use dialoguer::theme::Theme;
-use dialoguer::Confirm;
-use env_logger::{Builder, WriteStyle};
-use log::{Level, LevelFilter};
+use dialoguer::{Confirm, Input, Select};
+use env_logger::{Builder, Logger, WriteStyle};
+use log::{
+	kv::{self, Key, Value, VisitSource},
+	Level, LevelFilter, Log, Metadata, Record,
+};
+use serde_json::{json, Map};
 use std::fmt::{Display, Formatter};
-use std::{fmt, io::Write};
-
-use crate::util;
+use std::{
+	collections::{HashMap, VecDeque},
+	fmt,
+	fs::{self, File, OpenOptions},
+	io::{self, Write},
+	path::PathBuf,
+	str::FromStr,
+	sync::Mutex,
+};
+
+use crate::{ext::PathExt, lock, util};
 
 // These Vasc logs ignore verbosity level, aside of `Off`
 #[macro_export]
@@ -40,14 +54,264 @@ macro_rules! argon_info {
 	($($arg:tt)+) => ($crate::vasc_info!($($arg)+))
 }
 
-pub fn init(verbosity: LevelFilter, log_style: WriteStyle) {
+const MAX_RECENT_LOGS: usize = 200;
+
+// Last log lines kept in memory so they can be included in crash reports
+static RECENT_LOGS: Mutex<VecDeque<String>> = Mutex::new(VecDeque::new());
+
+/// Returns the most recent log lines, oldest first
+pub fn recent_logs() -> Vec<String> {
+	let recent = RECENT_LOGS.lock().unwrap_or_else(|err| err.into_inner());
+	recent.iter().cloned().collect()
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, ValueEnum)]
+pub enum LogFormat {
+	#[default]
+	Text,
+	Json,
+}
+
+/// Collects structured fields attached to the log record,
+/// e.g. `info!(path:? = path, session = id; "Message")`
+struct JsonFields(Map<String, serde_json::Value>);
+
+impl<'kvs> VisitSource<'kvs> for JsonFields {
+	fn visit_pair(&mut self, key: Key<'kvs>, value: Value<'kvs>) -> Result<(), kv::Error> {
+		self.0.insert(key.to_string(), json!(value.to_string()));
+		Ok(())
+	}
+}
+
+/// Formats record as a plain single line with timestamp
+fn format_text(record: &Record) -> String {
+	format!(
+		"{} {} [{}] {}",
+		Local::now().to_rfc3339(),
+		record.level(),
+		record.module_path().unwrap_or(record.target()),
+		record.args()
+	)
+}
+
+/// Formats record as a single line JSON object
+fn format_json(record: &Record) -> String {
+	let mut fields = JsonFields(Map::new());
+	record.key_values().visit(&mut fields).ok();
+
+	let object = json!({
+		"ts": Local::now().to_rfc3339(),
+		"level": record.level().as_str(),
+		"module": record.module_path().unwrap_or(record.target()),
+		"message": record.args().to_string(),
+		"fields": fields.0,
+	});
+
+	object.to_string()
+}
+
+/// Log file that is rotated once it exceeds `max_size`
+/// bytes or when it was last written on a previous day
+pub struct LogFile {
+	path: PathBuf,
+	file: File,
+	size: u64,
+	created: DateTime<Local>,
+	max_size: u64,
+	max_files: u32,
+}
+
+impl LogFile {
+	pub fn new(path: PathBuf, max_size: u64, max_files: u32) -> io::Result<Self> {
+		fs::create_dir_all(path.get_parent())?;
+
+		let file = OpenOptions::new().create(true).append(true).open(&path)?;
+		let metadata = file.metadata()?;
+
+		let created = metadata
+			.modified()
+			.map(DateTime::<Local>::from)
+			.unwrap_or_else(|_| Local::now());
+
+		let mut log_file = Self {
+			path,
+			file,
+			size: metadata.len(),
+			created,
+			max_size,
+			max_files,
+		};
+
+		if log_file.should_rotate() {
+			log_file.rotate()?;
+		}
+
+		Ok(log_file)
+	}
+
+	fn should_rotate(&self) -> bool {
+		(self.max_size > 0 && self.size >= self.max_size) || self.created.date_naive() != Local::now().date_naive()
+	}
+
+	fn rotate(&mut self) -> io::Result<()> {
+		let rotated = |index: u32| self.path.with_extension(format!("log.{index}"));
+
+		if self.max_files > 0 {
+			fs::remove_file(rotated(self.max_files)).ok();
+
+			for index in (1..self.max_files).rev() {
+				fs::rename(rotated(index), rotated(index + 1)).ok();
+			}
+
+			fs::rename(&self.path, rotated(1))?;
+		}
+
+		self.file = File::create(&self.path)?;
+		self.size = 0;
+		self.created = Local::now();
+
+		Ok(())
+	}
+
+	/// Appends line to the file, rotating it first if needed
+	pub fn write(&mut self, line: &str) -> io::Result<()> {
+		if self.should_rotate() {
+			self.rotate()?;
+		}
+
+		self.file.write_all(line.as_bytes())?;
+		self.size += line.len() as u64;
+
+		Ok(())
+	}
+}
+
+/// Global verbosity combined with per-module levels from the `[log]` config table
+#[derive(Clone)]
+pub struct LevelFilters {
+	verbosity: LevelFilter,
+	modules: Vec<(String, LevelFilter)>,
+}
+
+impl LevelFilters {
+	/// Parses `module = level` pairs, returns filters
+	/// together with the pairs that have an invalid level
+	pub fn new(verbosity: LevelFilter, modules: &HashMap<String, String>) -> (Self, Vec<String>) {
+		let mut invalid = vec![];
+
+		let modules = modules
+			.iter()
+			.filter_map(|(module, level)| match LevelFilter::from_str(level) {
+				Ok(level) => {
+					// Allow using short names of Vasc modules, e.g. `server`,
+					// while still supporting full paths and other crates
+					let internal = format!("vasc::{}", module.trim_start_matches("vasc::"));
+
+					Some([(internal, level), (module.to_owned(), level)])
+				}
+				Err(_) => {
+					invalid.push(format!("{module} = {level}"));
+					None
+				}
+			})
+			.flatten()
+			.collect();
+
+		(Self { verbosity, modules }, invalid)
+	}
+
+	pub fn allows(&self, record: &Record) -> bool {
+		if record.target() == "vasc_log" {
+			return true;
+		}
+
+		let path = record.module_path().unwrap_or(record.target());
+
+		let level = self
+			.modules
+			.iter()
+			.filter(|(module, _)| path == module || path.starts_with(&format!("{module}::")))
+			.max_by_key(|(module, _)| module.len())
+			.map(|(_, level)| *level)
+			.unwrap_or(self.verbosity);
+
+		record.level() <= level
+	}
+}
+
+struct VascLogger {
+	inner: Logger,
+	filters: LevelFilters,
+	format: LogFormat,
+	file: Option<Mutex<LogFile>>,
+}
+
+impl Log for VascLogger {
+	fn enabled(&self, metadata: &Metadata) -> bool {
+		self.inner.enabled(metadata)
+	}
+
+	fn log(&self, record: &Record) {
+		if !self.inner.matches(record) {
+			return;
+		}
+
+		self.inner.log(record);
+
+		if !self.filters.allows(record) {
+			return;
+		}
+
+		let line = format_text(record);
+
+		if let Some(file) = &self.file {
+			let line = match self.format {
+				LogFormat::Text => line.clone() + "\n",
+				LogFormat::Json => format_json(record) + "\n",
+			};
+
+			lock!(file).write(&line).ok();
+		}
+
+		let mut recent = RECENT_LOGS.lock().unwrap_or_else(|err| err.into_inner());
+
+		if recent.len() >= MAX_RECENT_LOGS {
+			recent.pop_front();
+		}
+
+		recent.push_back(line);
+	}
+
+	fn flush(&self) {
+		self.inner.flush();
+
+		if let Some(file) = &self.file {
+			lock!(file).file.flush().ok();
+		}
+	}
+}
+
+pub fn init(
+	verbosity: LevelFilter,
+	log_style: WriteStyle,
+	log_format: LogFormat,
+	log_file: Option<LogFile>,
+	modules: &HashMap<String, String>,
+) {
 	let mut builder = Builder::new();
+	let (filters, invalid) = LevelFilters::new(verbosity, modules);
+
+	let format_filters = filters.clone();
 
 	builder.format(move |buffer, record| {
-		if record.level() > verbosity && record.target() != "vasc_log" {
+		if !format_filters.allows(record) {
 			return Ok(());
 		}
 
+		if log_format == LogFormat::Json {
+			return writeln!(buffer, "{}", format_json(record));
+		}
+
 		let color = match record.level() {
 			Level::Error => Color::Red,
 			Level::Warn => Color::Yellow,
@@ -98,7 +362,27 @@ pub fn init(verbosity: LevelFilter, log_style: WriteStyle) {
 
 	builder.filter_module("rbx_binary", LevelFilter::Warn);
 
-	builder.init();
+	for (module, level) in &filters.modules {
+		builder.filter_module(module, *level);
+	}
+
+	let inner = builder.build();
+	let max_level = inner.filter();
+
+	let logger = VascLogger {
+		inner,
+		filters,
+		format: log_format,
+		file: log_file.map(Mutex::new),
+	};
+
+	if log::set_boxed_logger(Box::new(logger)).is_ok() {
+		log::set_max_level(max_level);
+	}
+
+	for filter in invalid {
+		log::warn!("Invalid log level filter in config: {filter}");
+	}
 }
 
 pub fn prompt(prompt: &str, default: bool) -> bool {
@@ -119,6 +403,44 @@ pub fn prompt(prompt: &str, default: bool) -> bool {
 	result.unwrap_or(default)
 }
 
+pub fn input(prompt: &str, default: &str) -> String {
+	if util::env_yes() {
+		return default.to_owned();
+	}
+
+	let theme = match util::env_log_style() {
+		WriteStyle::Always => PromptTheme::color(),
+		_ => PromptTheme::no_color(),
+	};
+
+	let result = Input::with_theme(&theme)
+		.with_prompt(prompt)
+		.default(default.to_owned())
+		.allow_empty(true)
+		.interact_text();
+
+	result.unwrap_or(default.to_owned())
+}
+
+pub fn select(prompt: &str, items: &[String], default: usize) -> usize {
+	if util::env_yes() {
+		return default;
+	}
+
+	let theme = match util::env_log_style() {
+		WriteStyle::Always => PromptTheme::color(),
+		_ => PromptTheme::no_color(),
+	};
+
+	let result = Select::with_theme(&theme)
+		.with_prompt(prompt)
+		.items(items)
+		.default(default)
+		.interact();
+
+	result.unwrap_or(default)
+}
+
 pub struct Table {
 	rows: Vec<Vec<String>>,
 	columns: Vec<usize>,
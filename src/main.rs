@@ -7,20 +7,33 @@ use std::{
 	mem::ManuallyDrop,
 	process::ExitCode,
 	thread,
+	time::Instant,
 };
 
-use vasc::{argon_error, cli::Cli, config::Config, crash_handler, installer, logger, stats, updater};
+use vasc::{
+	argon_error,
+	cli::Cli,
+	config::Config,
+	crash_handler, installer,
+	logger::{self, LogFile, LogFormat},
+	processes, stats, telemetry, updater, util, wizard,
+};
 
 const PROFILER_ADDRESS: &str = "localhost:8888";
 
 fn main() -> ExitCode {
 	crash_handler::hook();
 
+	let first_run = wizard::is_first_run();
+
 	let config_kind = Config::load();
-	let config = Config::new().clone();
+	let mut config = Config::new().clone();
 
 	let is_managed = installer::is_managed();
-	let installation = installer::verify(is_managed, config.install_plugin);
+
+	// On the first run installation is performed by the setup wizard once logger is ready
+	let installation =
+		(!first_run).then(|| installer::verify(is_managed, config.install_plugin, config.update_channel == "beta"));
 
 	let cli = Cli::new();
 
@@ -28,6 +41,8 @@ fn main() -> ExitCode {
 	let backtrace = cli.backtrace();
 	let verbosity = cli.verbosity();
 	let log_style = cli.log_style();
+	let log_format = cli.log_format();
+	let log_file = cli.log_file();
 
 	if log_style == WriteStyle::Auto && io::stdin().is_terminal() {
 		env::set_var("RUST_LOG_STYLE", "always");
@@ -45,18 +60,57 @@ fn main() -> ExitCode {
 	env::set_var("RUST_YES", if yes { "1" } else { "0" });
 	env::set_var("RUST_BACKTRACE", if backtrace { "1" } else { "0" });
 
-	logger::init(verbosity, log_style);
+	env::set_var(
+		"RUST_LOG_FORMAT",
+		match log_format {
+			LogFormat::Json => "json",
+			LogFormat::Text => "text",
+		},
+	);
+
+	if let Some(path) = &log_file {
+		env::set_var("RUST_LOG_FILE", path);
+	}
+
+	let log_file = log_file.or_else(|| {
+		config
+			.log_to_file
+			.then(|| util::get_vasc_dir().ok().map(|dir| dir.join("logs").join("vasc.log")))
+			.flatten()
+	});
+
+	let log_file =
+		log_file.and_then(
+			|path| match LogFile::new(path, config.log_max_size * 1_048_576, config.log_max_files) {
+				Ok(file) => Some(file),
+				Err(err) => {
+					eprintln!("Failed to open log file: {err}");
+					None
+				}
+			},
+		);
+
+	logger::init(verbosity, log_style, log_format, log_file, &config.log);
 
 	match config_kind {
 		Ok(kind) => info!("{kind:?} config loaded"),
 		Err(err) => error!("Failed to load config file: {err}"),
 	}
 
+	let installation = installation.unwrap_or_else(|| {
+		let result = wizard::run(is_managed);
+		config = Config::new().clone();
+
+		result
+	});
+
 	match installation {
 		Ok(()) => info!("Argon installation verified successfully!"),
 		Err(err) => warn!("Failed to verify Argon installation: {err}"),
 	}
 
+	let share_telemetry = config.telemetry;
+
 	let handle = thread::spawn(move || {
 		if !is_managed && config.check_updates {
 			match updater::check_for_updates(config.install_plugin, config.update_templates, !config.auto_update) {
@@ -88,6 +142,9 @@ fn main() -> ExitCode {
 		puffin::set_scopes_on(true);
 	}
 
+	let command = cli.command_name();
+	let start = Instant::now();
+
 	let exit_code = match cli.main() {
 		Ok(()) => {
 			debug!("Successfully executed command!");
@@ -99,8 +156,17 @@ fn main() -> ExitCode {
 		}
 	};
 
+	processes::terminate_children();
+
 	handle.join().ok();
 	stats::save().ok();
 
+	if share_telemetry {
+		match telemetry::record(command, start.elapsed(), exit_code == ExitCode::SUCCESS) {
+			Ok(()) => debug!("Telemetry event recorded"),
+			Err(err) => warn!("Failed to record telemetry: {err}"),
+		}
+	}
+
 	exit_code
 }
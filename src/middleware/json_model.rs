@@ -1,11 +1,11 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use log::error;
 use rbx_dom_weak::{types::Tags, ustr, HashMapExt, Ustr, UstrMap};
 use serde::Deserialize;
 use std::path::Path;
 
 use super::helpers;
-use crate::{core::snapshot::Snapshot, resolution::UnresolvedValue, vfs::Vfs};
+use crate::{core::snapshot::Snapshot, resolution::UnresolvedValue, util, vfs::Vfs};
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -34,7 +34,7 @@ pub fn read_json_model(path: &Path, vfs: &Vfs) -> Result<Snapshot> {
 		return Ok(Snapshot::new().with_class("Folder"));
 	}
 
-	let model = serde_json::from_str(&contents)?;
+	let model = serde_json::from_str(&contents).map_err(|err| anyhow!(util::format_json_error(&contents, &err)))?;
 	let snapshot = walk(model, path)?;
 
 	Ok(snapshot)
@@ -13,7 +13,7 @@ use std::{
 
 use self::data::DataSnapshot;
 use crate::{
-	argon_warn,
+	argon_warn, assets,
 	constants::BLACKLISTED_PATHS,
 	core::{
 		meta::{Context, Source},
@@ -213,6 +213,8 @@ fn new_snapshot_file(path: &Path, context: &Context, vfs: &Vfs) -> Result<Option
 			snapshot.apply_data(instance_data);
 		}
 
+		assets::substitute(&mut snapshot, context);
+
 		Ok(Some(snapshot))
 	} else {
 		Ok(None)
@@ -261,6 +263,8 @@ fn new_snapshot_file_child(path: &Path, context: &Context, vfs: &Vfs) -> Result<
 			snapshot.apply_data(instance_data);
 		}
 
+		assets::substitute_properties(&mut snapshot.properties, context);
+
 		Ok(Some(snapshot))
 	} else {
 		Ok(None)
@@ -276,6 +280,8 @@ fn new_snapshot_dir(path: &Path, context: &Context, vfs: &Vfs) -> Result<Option<
 		snapshot.apply_data(instance_data);
 	}
 
+	assets::substitute_properties(&mut snapshot.properties, context);
+
 	Ok(Some(snapshot))
 }
 
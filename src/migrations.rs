@@ -0,0 +1,103 @@
+use anyhow::{bail, Result};
+use log::{debug, warn};
+use serde::{de::DeserializeOwned, Serialize};
+use std::{fs, path::Path};
+use toml::{Table, Value};
+
+/// Key that stores the format version of every state file
+const VERSION_KEY: &str = "format_version";
+
+/// Single upgrade step, migration at index `n` upgrades
+/// the table from format version `n` to version `n + 1`
+pub type Migration = fn(&mut Table);
+
+/// Migrations of the `config.toml` and `argon.toml` files,
+/// these files are edited by users so the version is never
+/// stored in them and all migrations have to be idempotent
+pub const CONFIG: &[Migration] = &[rename_auto_detect];
+
+/// Migrations of the `update.toml` file
+pub const UPDATE_STATUS: &[Migration] = &[];
+
+/// Migrations of the `sessions.toml` file
+pub const SESSIONS: &[Migration] = &[];
+
+/// Migrations of the `stats.toml` file
+pub const STATS: &[Migration] = &[];
+
+/// Migrations of the `telemetry.toml` file
+pub const TELEMETRY: &[Migration] = &[];
+
+/// Apply all pending migrations to the table,
+/// returns `true` if any migration was applied
+pub fn upgrade(table: &mut Table, migrations: &[Migration]) -> bool {
+	let version = table
+		.remove(VERSION_KEY)
+		.and_then(|version| version.as_integer())
+		.unwrap_or(0)
+		.max(0) as usize;
+
+	if version >= migrations.len() {
+		return false;
+	}
+
+	for (index, migrate) in migrations.iter().enumerate().skip(version) {
+		debug!("Migrating from format version {} to {}", index, index + 1);
+		migrate(table);
+	}
+
+	true
+}
+
+/// Read state file and upgrade it to the latest format version,
+/// file that can't be read is backed up instead of being discarded
+pub fn read<T: DeserializeOwned + Serialize>(path: &Path, migrations: &[Migration]) -> Result<T> {
+	let result = || -> Result<(T, bool)> {
+		let mut table: Table = toml::from_str(&fs::read_to_string(path)?)?;
+		let migrated = upgrade(&mut table, migrations);
+
+		Ok((table.try_into()?, migrated))
+	}();
+
+	match result {
+		Ok((value, migrated)) => {
+			if migrated {
+				write(path, &value, migrations)?;
+			}
+
+			Ok(value)
+		}
+		Err(err) => {
+			let backup = path.with_extension("toml.bak");
+
+			match fs::copy(path, &backup) {
+				Ok(_) => warn!("Backed up unreadable state file to: {}", backup.display()),
+				Err(err) => warn!("Failed to back up unreadable state file: {}", err),
+			}
+
+			Err(err)
+		}
+	}
+}
+
+/// Write state file stamped with the latest format version
+pub fn write<T: Serialize>(path: &Path, value: &T, migrations: &[Migration]) -> Result<()> {
+	let mut table = match Value::try_from(value)? {
+		Value::Table(table) => table,
+		_ => bail!("State has to be serialized as a table"),
+	};
+
+	table.insert(VERSION_KEY.into(), Value::Integer(migrations.len() as i64));
+
+	fs::write(path, toml::to_string(&table)?)?;
+
+	Ok(())
+}
+
+// Config migrations
+
+fn rename_auto_detect(table: &mut Table) {
+	if let Some(value) = table.remove("auto_detect") {
+		table.entry("detect_project").or_insert(value);
+	}
+}
@@ -0,0 +1,194 @@
+use anyhow::{anyhow, bail, Context, Result};
+use colored::Colorize;
+use reqwest::{
+	blocking::{
+		multipart::{Form, Part},
+		Client, Response,
+	},
+	header::CONTENT_TYPE,
+};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::{
+	env, fs,
+	path::Path,
+	str::FromStr,
+	thread,
+	time::{Duration, Instant},
+};
+
+use crate::{config::Config, ext::PathExt};
+
+const ASSETS_URL: &str = "https://apis.roblox.com/assets/v1";
+const UNIVERSES_URL: &str = "https://apis.roblox.com/universes/v1";
+const UPLOAD_TIMEOUT: Duration = Duration::from_secs(60);
+const PUBLISH_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Environment variable that holds the Open Cloud API key
+pub const API_KEY_VAR: &str = "VASC_API_KEY";
+
+/// Owner of the uploaded assets, parsed from `user:<id>` or `group:<id>`
+#[derive(Debug, Clone, Copy)]
+pub enum Creator {
+	User(u64),
+	Group(u64),
+}
+
+impl Creator {
+	fn to_json(self) -> Value {
+		match self {
+			Creator::User(id) => json!({ "userId": id.to_string() }),
+			Creator::Group(id) => json!({ "groupId": id.to_string() }),
+		}
+	}
+}
+
+impl FromStr for Creator {
+	type Err = anyhow::Error;
+
+	fn from_str(creator: &str) -> Result<Self> {
+		let (kind, id) = creator.split_once(':').ok_or_else(|| {
+			anyhow!(
+				"Creator has to be in {} or {} format",
+				"user:<id>".bold(),
+				"group:<id>".bold()
+			)
+		})?;
+
+		let id = id.trim().parse()?;
+
+		match kind.trim() {
+			"user" => Ok(Creator::User(id)),
+			"group" => Ok(Creator::Group(id)),
+			kind => bail!("Unknown creator type: {}", kind.bold()),
+		}
+	}
+}
+
+#[derive(Deserialize)]
+struct Operation {
+	path: String,
+	#[serde(default)]
+	done: bool,
+	response: Option<OperationResponse>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OperationResponse {
+	asset_id: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PublishResponse {
+	version_number: u64,
+}
+
+/// Returns the Open Cloud API key from the environment or the file set in `api_key_file`
+pub fn get_api_key() -> Result<String> {
+	if let Ok(api_key) = env::var(API_KEY_VAR) {
+		return Ok(api_key);
+	}
+
+	let path = Config::new().api_key_file.clone();
+
+	if !path.is_empty() {
+		let api_key = fs::read_to_string(&path)
+			.with_context(|| format!("Failed to read Open Cloud API key from {}", path.bold()))?;
+		let api_key = api_key.trim();
+
+		if api_key.is_empty() {
+			bail!("Open Cloud API key file {} is empty", path.bold());
+		}
+
+		return Ok(api_key.to_owned());
+	}
+
+	bail!(
+		"Open Cloud API key is missing, set it in the {} environment variable or point {} setting to a file containing it",
+		API_KEY_VAR.bold(),
+		"api_key_file".bold()
+	)
+}
+
+/// Upload file using the Open Cloud Assets API and wait until it gets processed,
+/// returns the ID of the created asset
+pub fn upload_asset(api_key: &str, path: &Path, asset_type: &str, mime: &str, creator: Creator) -> Result<u64> {
+	let client = Client::new();
+
+	let request = json!({
+		"assetType": asset_type,
+		"displayName": path.get_stem().chars().take(50).collect::<String>(),
+		"description": "Uploaded by Vasc",
+		"creationContext": {
+			"creator": creator.to_json(),
+		},
+	});
+
+	let file = Part::bytes(fs::read(path)?)
+		.file_name(path.get_name().to_owned())
+		.mime_str(mime)?;
+
+	let form = Form::new()
+		.text("request", request.to_string())
+		.part("fileContent", file);
+
+	let response = client
+		.post(format!("{ASSETS_URL}/assets"))
+		.header("x-api-key", api_key)
+		.multipart(form)
+		.send()?;
+
+	let mut operation: Operation = check(response)?.json()?;
+	let started = Instant::now();
+
+	while !operation.done {
+		if started.elapsed() > UPLOAD_TIMEOUT {
+			bail!("Timed out waiting for {} to be processed", path.get_name().bold());
+		}
+
+		thread::sleep(Duration::from_secs(1));
+
+		let response = client
+			.get(format!("{ASSETS_URL}/{}", operation.path))
+			.header("x-api-key", api_key)
+			.send()?;
+
+		operation = check(response)?.json()?;
+	}
+
+	let response = operation.response.context("Asset upload finished without a response")?;
+
+	Ok(response.asset_id.parse()?)
+}
+
+/// Upload place file as a new version of the place, it gets published
+/// if `publish` is set, otherwise it's only saved, returns the version number
+pub fn publish_place(api_key: &str, universe_id: u64, place_id: u64, path: &Path, publish: bool) -> Result<u64> {
+	let version_type = if publish { "Published" } else { "Saved" };
+
+	let response = Client::new()
+		.post(format!("{UNIVERSES_URL}/{universe_id}/places/{place_id}/versions"))
+		.query(&[("versionType", version_type)])
+		.header("x-api-key", api_key)
+		.header(CONTENT_TYPE, "application/octet-stream")
+		.timeout(PUBLISH_TIMEOUT)
+		.body(fs::read(path)?)
+		.send()?;
+
+	let response: PublishResponse = check(response)?.json()?;
+
+	Ok(response.version_number)
+}
+
+/// Turns unsuccessful response into an error that includes the response body
+pub(crate) fn check(response: Response) -> Result<Response> {
+	let status = response.status();
+
+	if status.is_success() {
+		return Ok(response);
+	}
+
+	bail!("Open Cloud request failed ({}): {}", status, response.text()?.trim())
+}
@@ -0,0 +1,70 @@
+use log::{debug, trace};
+use std::{
+	process::{self, Child, Command},
+	sync::Mutex,
+};
+
+use crate::sessions;
+
+// Children spawned by this Vasc process that should not outlive it,
+// like roblox-ts watchers started together with `serve` or `build`
+static CHILDREN: Mutex<Vec<Child>> = Mutex::new(Vec::new());
+
+/// Keeps handle of the spawned child so it can be terminated when Vasc exits
+pub fn track(child: Child) {
+	trace!("Tracking child process: {}", child.id());
+
+	CHILDREN.lock().unwrap_or_else(|err| err.into_inner()).push(child);
+}
+
+/// Terminates all tracked children that are still running
+pub fn terminate_children() {
+	let mut children = CHILDREN.lock().unwrap_or_else(|err| err.into_inner());
+
+	for mut child in children.drain(..) {
+		if let Ok(None) = child.try_wait() {
+			debug!("Terminating child process: {}", child.id());
+
+			terminate(child.id());
+			child.wait().ok();
+		}
+	}
+}
+
+/// Terminates the process with the given `pid` together with its children,
+/// tracked children are spawned in their own process group on UNIX
+pub fn terminate(pid: u32) {
+	#[cfg(not(target_os = "windows"))]
+	{
+		// Kill the whole process group if the process leads one
+		Command::new("kill").arg("--").arg(format!("-{pid}")).output().ok();
+
+		// Kill main process
+		Command::new("kill").arg(pid.to_string()).output().ok();
+
+		// Kill child processes
+		Command::new("pkill").arg("-P").arg(pid.to_string()).output().ok();
+	}
+
+	// Kill both main and child processes
+	#[cfg(target_os = "windows")]
+	Command::new("TASKKILL")
+		.arg("/F")
+		.arg("/T")
+		.args(["/PID", &pid.to_string()])
+		.output()
+		.ok();
+}
+
+/// Terminates tracked children, removes session entries
+/// of the current process and exits with the given `code`
+pub fn shutdown(code: i32) -> ! {
+	terminate_children();
+
+	match sessions::remove_by_pid(process::id()) {
+		Ok(()) => trace!("Session entries removed"),
+		Err(err) => debug!("Failed to remove session entries: {err}"),
+	}
+
+	process::exit(code)
+}
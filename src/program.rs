@@ -8,7 +8,7 @@ use std::{
 	process::{Child, Command, Output, Stdio},
 };
 
-use crate::{config::Config, ext::WriteStyleExt, logger, util, vasc_error};
+use crate::{config::Config, ext::WriteStyleExt, logger, processes, util, vasc_error};
 
 #[derive(PartialEq)]
 pub enum ProgramName {
@@ -17,6 +17,9 @@ pub enum ProgramName {
 	Npm,
 	Npx,
 	Wally,
+	StyLua,
+	Selene,
+	LuauLsp,
 }
 
 pub struct Program {
@@ -85,6 +88,25 @@ impl Program {
 		}
 	}
 
+	/// Spawns child in its own process group and keeps
+	/// track of it so it's terminated together with Vasc
+	pub fn spawn_tracked(&mut self) -> Result<Option<u32>> {
+		let mut command = self.get_command();
+
+		#[cfg(unix)]
+		std::os::unix::process::CommandExt::process_group(&mut command, 0);
+
+		match command.spawn() {
+			Ok(child) => {
+				let pid = child.id();
+				processes::track(child);
+
+				Ok(Some(pid))
+			}
+			Err(err) => self.handle_error(err),
+		}
+	}
+
 	pub fn output(&mut self) -> Result<Option<Output>> {
 		let result = self.get_command().output();
 
@@ -124,6 +146,9 @@ impl Program {
 			(ProgramName::Npx, _) => package_manager,
 			(ProgramName::Git, _) => "git",
 			(ProgramName::Wally, _) => "wally",
+			(ProgramName::StyLua, _) => "stylua",
+			(ProgramName::Selene, _) => "selene",
+			(ProgramName::LuauLsp, _) => "luau-lsp",
 			(ProgramName::Argon, _) => unreachable!(),
 		}
 		.to_owned();
@@ -179,6 +204,19 @@ impl Program {
 				)
 			}
 			ProgramName::Wally => format!("{}: {} is not installed", error, "Wally"),
+			ProgramName::StyLua => format!(
+				"{}: {} is not installed. To suppress this message disable {} setting",
+				error,
+				"StyLua".bold(),
+				"format_on_sync".bold()
+			),
+			ProgramName::Selene => format!(
+				"{}: {} is not installed. To suppress this message disable {} setting",
+				error,
+				"selene".bold(),
+				"lint_on_sync".bold()
+			),
+			ProgramName::LuauLsp => format!("{}: {} is not installed", error, "luau-lsp".bold()),
 			ProgramName::Argon => unreachable!(),
 		}
 	}
@@ -190,6 +228,9 @@ impl Program {
 			ProgramName::Git => "Git",
 			ProgramName::Npm | ProgramName::Npx => &config.package_manager,
 			ProgramName::Wally => "Wally",
+			ProgramName::StyLua => "StyLua",
+			ProgramName::Selene => "selene",
+			ProgramName::LuauLsp => "luau-lsp",
 			ProgramName::Argon => unreachable!(),
 		};
 
@@ -208,6 +249,9 @@ impl Program {
 			}
 			.to_owned(),
 			ProgramName::Wally => "https://wally.run".into(),
+			ProgramName::StyLua => "https://github.com/JohnnyMorganz/StyLua#installation".into(),
+			ProgramName::Selene => "https://kampfkarren.github.io/selene/selene.html".into(),
+			ProgramName::LuauLsp => "https://github.com/JohnnyMorganz/luau-lsp/releases".into(),
 			ProgramName::Argon => unreachable!(),
 		}
 	}
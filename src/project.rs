@@ -11,6 +11,7 @@ use std::{
 
 use crate::{
 	config::Config,
+	constants::{default_sync_rules, SERVER_CAPABILITIES},
 	core::{
 		meta::{NodePath, SyncRule},
 		tree::Tree,
@@ -18,7 +19,7 @@ use crate::{
 	ext::{PathExt, ResultExt},
 	glob::Glob,
 	resolution::UnresolvedValue,
-	util::get_json_formatter,
+	util::{self, get_json_formatter},
 };
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -62,6 +63,14 @@ pub struct ProjectNode {
 	pub keep_unknowns: Option<bool>,
 }
 
+/// Place that `vasc publish` uploads the project to
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PublishProfile {
+	pub universe_id: u64,
+	pub place_id: u64,
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct SyncbackSettings {
@@ -100,6 +109,8 @@ pub struct Project {
 
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub syncback: Option<SyncbackSettings>,
+	#[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+	pub publish: BTreeMap<String, PublishProfile>,
 
 	#[serde(alias = "emitLegacyScripts", skip_serializing_if = "Option::is_none")]
 	pub legacy_scripts: Option<bool>,
@@ -116,12 +127,14 @@ pub struct Project {
 impl Project {
 	pub fn load(project_path: &Path) -> Result<Self> {
 		let project = fs::read_to_string(project_path)?;
-		let mut project: Project = serde_json::from_str(&project).with_desc(|| {
-			format!(
-				"Failed to parse project at {}",
-				project_path.display().to_string().bold()
-			)
-		})?;
+		let mut project: Project = serde_json::from_str(&project)
+			.map_err(|err| util::format_json_error(&project, &err))
+			.with_desc(|| {
+				format!(
+					"Failed to parse project at {}",
+					project_path.display().to_string().bold()
+				)
+			})?;
 
 		let workspace_dir = project_path.get_parent();
 
@@ -259,6 +272,20 @@ pub fn resolve(path: PathBuf) -> Result<PathBuf> {
 	}
 }
 
+/// Returns all project files next to the given one, used
+/// to detect workspaces that contain multiple places
+pub fn get_places(project_path: &Path) -> Result<Vec<PathBuf>> {
+	let glob = project_path.get_parent().join("*.project.json");
+	let mut places = Glob::from_path(&glob)?
+		.iter()
+		.filter_map(|path| path.ok())
+		.collect::<Vec<_>>();
+
+	places.sort();
+
+	Ok(places)
+}
+
 #[derive(Debug, Clone, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct ProjectDetails {
@@ -267,6 +294,12 @@ pub struct ProjectDetails {
 	game_id: Option<u64>,
 	place_ids: Vec<u64>,
 	root_refs: Vec<Ref>,
+	/// Filesystem paths mapped in the project tree
+	root_paths: Vec<PathBuf>,
+	/// Sync rules in effect (project rules or the defaults)
+	sync_rules: Vec<SyncRule>,
+	/// Features supported by the server
+	capabilities: Vec<String>,
 	/// All ignore patterns (from project JSON + .vascignore) for the plugin
 	ignore_patterns: Vec<String>,
 	/// Hash of ignore_patterns so the plugin can detect changes
@@ -301,12 +334,36 @@ impl ProjectDetails {
 				vec![tree.root_ref()]
 			},
 
+			root_paths: collect_paths(&project.node),
+
+			sync_rules: if project.sync_rules.is_empty() {
+				default_sync_rules().clone()
+			} else {
+				project.sync_rules.clone()
+			},
+
+			capabilities: SERVER_CAPABILITIES.iter().map(|c| c.to_string()).collect(),
+
 			ignore_patterns,
 			ignore_hash,
 		}
 	}
 }
 
+fn collect_paths(node: &ProjectNode) -> Vec<PathBuf> {
+	let mut paths = vec![];
+
+	if let Some(path) = &node.path {
+		paths.push(path.path().to_owned());
+	}
+
+	for child in node.tree.values() {
+		paths.extend(collect_paths(child));
+	}
+
+	paths
+}
+
 fn default_project_name() -> String {
 	String::from("default")
 }
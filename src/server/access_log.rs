@@ -0,0 +1,109 @@
+use actix_web::{
+	body::{BodySize, MessageBody},
+	dev::{ServiceRequest, ServiceResponse},
+	middleware::Next,
+	web::Data,
+	Error,
+};
+use chrono::Local;
+use log::{warn, Level, LevelFilter};
+use std::{
+	fs::{File, OpenOptions},
+	io::Write,
+	path::Path,
+	str::FromStr,
+	sync::Mutex,
+	time::Instant,
+};
+
+use crate::{config::Config, lock};
+
+/// Request logger shared by all server workers
+pub struct AccessLog {
+	level: Option<Level>,
+	file: Option<Mutex<File>>,
+}
+
+impl AccessLog {
+	pub fn from_config(config: &Config) -> Self {
+		let level = LevelFilter::from_str(&config.request_log_level)
+			.unwrap_or_else(|_| {
+				warn!(
+					"Invalid request_log_level: {}, falling back to trace",
+					config.request_log_level
+				);
+				LevelFilter::Trace
+			})
+			.to_level();
+
+		let file = if config.access_log.is_empty() {
+			None
+		} else {
+			match OpenOptions::new()
+				.create(true)
+				.append(true)
+				.open(Path::new(&config.access_log))
+			{
+				Ok(file) => Some(Mutex::new(file)),
+				Err(err) => {
+					warn!("Failed to open access log file {}: {err}", config.access_log);
+					None
+				}
+			}
+		};
+
+		Self { level, file }
+	}
+
+	fn record(&self, line: &str) {
+		if let Some(level) = self.level {
+			log::log!(level, "{line}");
+		}
+
+		if let Some(file) = &self.file {
+			if let Err(err) = writeln!(lock!(file), "{} {line}", Local::now().to_rfc3339()) {
+				warn!("Failed to write to access log: {err}");
+			}
+		}
+	}
+}
+
+/// Logs method, path, status, response size, latency and peer address of every request
+pub async fn middleware(
+	request: ServiceRequest,
+	next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+	let access_log = request.app_data::<Data<AccessLog>>().cloned();
+
+	let method = request.method().to_string();
+	let path = request.path().to_owned();
+	let peer = request
+		.peer_addr()
+		.map(|addr| addr.to_string())
+		.unwrap_or("unknown".into());
+
+	let start = Instant::now();
+	let result = next.call(request).await;
+
+	if let Some(access_log) = access_log {
+		let (status, size) = match &result {
+			Ok(response) => (response.status(), Some(response.response().body().size())),
+			Err(err) => (err.as_response_error().status_code(), None),
+		};
+
+		// Size of streamed bodies (like `/ws`) and error responses is not known yet
+		let size = match size {
+			Some(BodySize::Sized(size)) => size.to_string(),
+			Some(BodySize::None) => String::from("0"),
+			_ => String::from("-"),
+		};
+
+		access_log.record(&format!(
+			"{peer} \"{method} {path}\" {} {size} {}ms",
+			status.as_u16(),
+			start.elapsed().as_millis()
+		));
+	}
+
+	result
+}
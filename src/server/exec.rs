@@ -1,16 +1,62 @@
-use actix_msgpack::MsgPack;
-use actix_web::{post, web::Data, HttpResponse, Responder};
+use actix_msgpack::{MsgPack, MsgPackResponseBuilder};
+use actix_web::{
+	post,
+	web::{self, Data},
+	HttpResponse, Responder,
+};
+use crossbeam_channel::{Receiver, Sender};
+use lazy_static::lazy_static;
 use log::{error, trace};
-use serde::Deserialize;
-use std::sync::Arc;
+use serde::{Deserialize, Serialize};
+use std::{
+	collections::HashMap,
+	sync::{Arc, Mutex},
+	time::Instant,
+};
+use uuid::Uuid;
 
-use crate::{core::Core, server, studio};
+use crate::{constants::EXEC_TIMEOUT, core::Core, server, studio};
+
+lazy_static! {
+	/// Executions that are waiting for the plugin to report their output
+	pub(super) static ref PENDING: Mutex<HashMap<String, Sender<OutputChunk>>> = Mutex::new(HashMap::new());
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum OutputKind {
+	Print,
+	Warn,
+	Error,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+	pub kind: OutputKind,
+	pub message: String,
+}
+
+#[derive(Debug)]
+pub(super) struct OutputChunk {
+	pub output: Vec<Output>,
+	pub done: bool,
+}
+
+#[derive(Serialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+struct ExecResult {
+	output: Vec<Output>,
+	completed: bool,
+}
 
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 struct Request {
 	code: String,
 	focus: bool,
+	#[serde(default)]
+	wait: bool,
 }
 
 #[post("/exec")]
@@ -18,10 +64,21 @@ async fn main(request: MsgPack<Request>, core: Data<Arc<Core>>) -> impl Responde
 	trace!("Received request: exec");
 
 	let queue = core.queue();
+	let id = Uuid::new_v4().simple().to_string();
+
+	let receiver = if request.wait {
+		let (sender, receiver) = crossbeam_channel::unbounded();
+		PENDING.lock().unwrap().insert(id.clone(), sender);
+
+		Some(receiver)
+	} else {
+		None
+	};
 
 	let pushed = queue.push(
 		server::ExecuteCode {
 			code: request.code.clone(),
+			id: id.clone(),
 		},
 		None,
 	);
@@ -35,8 +92,37 @@ async fn main(request: MsgPack<Request>, core: Data<Arc<Core>>) -> impl Responde
 		}
 	}
 
-	match pushed {
-		Ok(()) => HttpResponse::Ok().body("Code executed successfully"),
-		Err(err) => HttpResponse::InternalServerError().body(err.to_string()),
+	let response = match (pushed, receiver) {
+		// Waiting blocks, so it must not happen on the worker that has to serve `/output`
+		(Ok(()), Some(receiver)) => match web::block(move || collect(receiver)).await {
+			Ok(result) => HttpResponse::Ok().msgpack(result),
+			Err(err) => HttpResponse::InternalServerError().body(err.to_string()),
+		},
+		(Ok(()), None) => HttpResponse::Ok().body("Code executed successfully"),
+		(Err(err), _) => HttpResponse::InternalServerError().body(err.to_string()),
+	};
+
+	PENDING.lock().unwrap().remove(&id);
+
+	response
+}
+
+fn collect(receiver: Receiver<OutputChunk>) -> ExecResult {
+	let deadline = Instant::now() + EXEC_TIMEOUT;
+	let mut result = ExecResult::default();
+
+	while let Some(remaining) = deadline.checked_duration_since(Instant::now()) {
+		let Ok(chunk) = receiver.recv_timeout(remaining) else {
+			break;
+		};
+
+		result.output.extend(chunk.output);
+
+		if chunk.done {
+			result.completed = true;
+			break;
+		}
 	}
+
+	result
 }
@@ -0,0 +1,34 @@
+use actix_web::{get, web::Data, HttpResponse, Responder};
+use log::trace;
+use serde::Serialize;
+use std::{path::PathBuf, sync::Arc};
+
+use crate::{core::Core, server::ServerState};
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Response {
+	version: &'static str,
+	name: String,
+	project: PathBuf,
+	state: &'static str,
+	clients: usize,
+	uptime: u64,
+}
+
+#[get("/health")]
+async fn main(core: Data<Arc<Core>>, state: Data<ServerState>) -> impl Responder {
+	trace!("Received request: health");
+
+	let project = core.project();
+	let clients = core.queue().client_count();
+
+	HttpResponse::Ok().json(Response {
+		version: env!("CARGO_PKG_VERSION"),
+		name: project.name.clone(),
+		project: project.path.clone(),
+		state: if clients > 0 { "syncing" } else { "idle" },
+		clients,
+		uptime: state.started.elapsed().as_secs(),
+	})
+}
@@ -0,0 +1,78 @@
+use actix_web::{
+	body::MessageBody,
+	dev::{ServiceRequest, ServiceResponse},
+	error::ErrorForbidden,
+	middleware::Next,
+	web::Data,
+	Error,
+};
+use anyhow::{Context, Result};
+use ipnet::IpNet;
+use log::warn;
+use std::net::IpAddr;
+
+/// Allowed and denied address ranges, parsed from
+/// `allowed_ips` and `denied_ips` settings
+pub struct IpFilter {
+	allowed: Vec<IpNet>,
+	denied: Vec<IpNet>,
+}
+
+impl IpFilter {
+	pub fn new(allowed: &str, denied: &str) -> Result<Self> {
+		Ok(Self {
+			allowed: parse_ranges(allowed).context("Invalid allowed_ips setting")?,
+			denied: parse_ranges(denied).context("Invalid denied_ips setting")?,
+		})
+	}
+
+	/// Loopback addresses are always allowed so Studio on the same
+	/// machine keeps working, denied ranges take precedence otherwise
+	pub fn check(&self, ip: IpAddr) -> bool {
+		let ip = ip.to_canonical();
+
+		if ip.is_loopback() {
+			return true;
+		}
+
+		if self.denied.iter().any(|range| range.contains(&ip)) {
+			return false;
+		}
+
+		self.allowed.is_empty() || self.allowed.iter().any(|range| range.contains(&ip))
+	}
+
+	fn is_empty(&self) -> bool {
+		self.allowed.is_empty() && self.denied.is_empty()
+	}
+}
+
+/// Parses comma separated CIDR ranges, single addresses match only themselves
+fn parse_ranges(ranges: &str) -> Result<Vec<IpNet>> {
+	ranges
+		.split(',')
+		.map(str::trim)
+		.filter(|range| !range.is_empty())
+		.map(|range| {
+			range
+				.parse::<IpNet>()
+				.or_else(|_| range.parse::<IpAddr>().map(IpNet::from))
+				.with_context(|| format!("{range} is not an IP address or CIDR range"))
+		})
+		.collect()
+}
+
+/// Rejects requests from addresses outside of the allowed ranges with `403 Forbidden`
+pub async fn middleware(
+	request: ServiceRequest,
+	next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+	if let (Some(filter), Some(peer)) = (request.app_data::<Data<IpFilter>>(), request.peer_addr()) {
+		if !filter.is_empty() && !filter.check(peer.ip()) {
+			warn!("Address {} is not allowed, rejecting {}", peer.ip(), request.path());
+			return Err(ErrorForbidden("Address not allowed"));
+		}
+	}
+
+	next.call(request).await
+}
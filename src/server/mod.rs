@@ -1,28 +1,49 @@
 use actix_msgpack::MsgPackConfig;
 use actix_web::{
+	middleware,
 	web::{self, Data},
-	App, HttpServer, Responder,
+	App, HttpRequest, HttpServer, Responder,
 };
 use derive_from_one::FromOne;
+use rustls::ServerConfig;
 use serde::{Deserialize, Serialize};
-use std::{io::Result, net::TcpListener, sync::Arc};
+use socket2::{Domain, Protocol, Socket, Type};
+use std::{
+	env, fs,
+	io::{Error, ErrorKind, Result},
+	net::{Ipv6Addr, SocketAddr, TcpListener},
+	path::Path,
+	sync::Arc,
+	time::Instant,
+};
+use uuid::Uuid;
 
+use self::{access_log::AccessLog, ip_filter::IpFilter, rate_limit::RateLimiter};
 use crate::{
-	constants::MAX_PAYLOAD_SIZE,
+	config::Config,
 	core::{changes::Changes, Core},
 	project::ProjectDetails,
 };
 
+mod access_log;
 mod details;
 mod exec;
+mod health;
 mod home;
+pub mod ip_filter;
 mod open;
+mod output;
+mod pull;
+pub mod rate_limit;
 mod read;
 mod snapshot;
 mod stop;
 mod subscribe;
+pub mod tls;
 mod unsubscribe;
+mod version;
 mod write;
+mod ws;
 
 #[derive(Debug, Clone, Serialize, FromOne)]
 pub enum Message {
@@ -30,6 +51,7 @@ pub enum Message {
 	SyncbackChanges(SyncbackChanges),
 	SyncDetails(SyncDetails),
 	ExecuteCode(ExecuteCode),
+	PullInstances(PullInstances),
 	Disconnect(Disconnect),
 }
 
@@ -49,8 +71,20 @@ pub struct SyncbackChanges();
 pub struct SyncDetails(pub ProjectDetails);
 
 #[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ExecuteCode {
 	pub code: String,
+	/// Identifier that the plugin reports the output with
+	pub id: String,
+}
+
+/// Asks the plugin to send instances at the given paths
+/// (e.g. `ReplicatedStorage.Shared`) back to the server as additions,
+/// empty list means every instance that the project tree maps
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PullInstances {
+	pub paths: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -64,31 +98,117 @@ pub struct AuthRequest {
 	client_id: u32,
 }
 
+/// Header used by clients to present the session token
+pub const TOKEN_HEADER: &str = "Vasc-Token";
+
+/// Environment variable that holds the session token
+pub const TOKEN_VAR: &str = "VASC_TOKEN";
+
+#[derive(Debug, Clone)]
+pub struct ServerState {
+	token: String,
+	started: Instant,
+	max_payload_size: usize,
+}
+
+impl ServerState {
+	/// Requests carrying a token must match this instance, so a stale
+	/// registry entry never affects another server that took the port.
+	/// Loopback peers are trusted, remote ones have to present the token
+	pub fn is_authorized(&self, request: &HttpRequest) -> bool {
+		if let Some(token) = request.headers().get(TOKEN_HEADER) {
+			return token.to_str().is_ok_and(|token| token == self.token);
+		}
+
+		request
+			.peer_addr()
+			.is_some_and(|addr| addr.ip().to_canonical().is_loopback())
+	}
+}
+
 pub struct Server {
 	core: Arc<Core>,
-	host: String,
-	port: u16,
+	listener: TcpListener,
+	token: String,
+	tls: Option<ServerConfig>,
 }
 
 impl Server {
-	pub fn new(core: Arc<Core>, host: &str, port: u16) -> Self {
+	pub fn new(core: Arc<Core>, listener: TcpListener) -> Self {
 		Self {
 			core,
-			host: host.to_owned(),
-			port,
+			listener,
+			token: Uuid::new_v4().simple().to_string(),
+			tls: None,
 		}
 	}
 
+	pub fn with_token(mut self, token: &str) -> Self {
+		self.token = token.to_owned();
+		self
+	}
+
+	pub fn with_tls(mut self, config: ServerConfig) -> Self {
+		self.tls = Some(config);
+		self
+	}
+
+	pub fn token(&self) -> &str {
+		&self.token
+	}
+
 	#[actix_web::main]
 	pub async fn start(&self) -> Result<()> {
 		let core = self.core.clone();
 
-		HttpServer::new(move || {
+		let (state, access_log, ip_filter, rate_limiter) = {
+			let config = Config::new();
+
+			let max_payload_size = config
+				.max_payload_size
+				.checked_mul(1_048_576)
+				.filter(|size| *size > 0)
+				.ok_or_else(|| {
+					Error::new(
+						ErrorKind::InvalidInput,
+						format!(
+							"Invalid max_payload_size setting: {}, it has to be at least 1 MiB",
+							config.max_payload_size
+						),
+					)
+				})?;
+
+			let ip_filter = IpFilter::new(&config.allowed_ips, &config.denied_ips)
+				.map_err(|err| Error::new(ErrorKind::InvalidInput, format!("{err:#}")))?;
+
+			let state = ServerState {
+				token: self.token.clone(),
+				started: Instant::now(),
+				max_payload_size,
+			};
+
+			(
+				state,
+				Data::new(AccessLog::from_config(&config)),
+				Data::new(ip_filter),
+				Data::new(RateLimiter::new(config.rate_limit)),
+			)
+		};
+
+		let server = HttpServer::new(move || {
 			let mut msgpack_config = MsgPackConfig::default();
-			msgpack_config.limit(MAX_PAYLOAD_SIZE);
+			msgpack_config.limit(state.max_payload_size);
 
 			App::new()
 				.app_data(Data::new(core.clone()))
+				.app_data(Data::new(state.clone()))
+				.app_data(access_log.clone())
+				.app_data(ip_filter.clone())
+				.app_data(rate_limiter.clone())
+				// Middleware registered last runs first, so rejected requests are logged too
+				.wrap(middleware::from_fn(rate_limit::middleware))
+				.wrap(middleware::from_fn(ip_filter::middleware))
+				.wrap(middleware::from_fn(access_log::middleware))
 				.app_data(msgpack_config)
 				.service(details::main)
 				.service(subscribe::main)
@@ -97,16 +217,26 @@ impl Server {
 				.service(read::main)
 				.service(write::main)
 				.service(exec::main)
+				.service(output::main)
+				.service(pull::main)
 				.service(open::main)
 				.service(stop::main)
+				.service(health::main)
+				.service(version::main)
+				.service(ws::main)
 				.service(home::main)
 				.default_service(web::to(Self::default_redirect))
 		})
 		.backlog(0)
-		.disable_signals()
-		.bind((self.host.clone(), self.port))?
-		.run()
-		.await
+		.disable_signals();
+
+		let listener = self.listener.try_clone()?;
+
+		if let Some(tls) = self.tls.clone() {
+			server.listen_rustls_0_23(listener, tls)?.run().await
+		} else {
+			server.listen(listener)?.run().await
+		}
 	}
 
 	async fn default_redirect() -> impl Responder {
@@ -114,25 +244,97 @@ impl Server {
 	}
 }
 
-pub fn is_port_free(host: &str, port: u16) -> bool {
-	TcpListener::bind((host, port)).is_ok()
+/// Binds the given address, the IPv6 wildcard (`::`) is bound
+/// as dual-stack so IPv4 clients can still connect to the server
+pub fn bind(host: &str, port: u16) -> Result<TcpListener> {
+	match host.parse::<Ipv6Addr>() {
+		Ok(address) if address.is_unspecified() => {
+			let socket = Socket::new(Domain::IPV6, Type::STREAM, Some(Protocol::TCP))?;
+
+			#[cfg(not(windows))]
+			socket.set_reuse_address(true)?;
+
+			socket.set_only_v6(false)?;
+			socket.bind(&SocketAddr::from((address, port)).into())?;
+			socket.listen(1024)?;
+
+			Ok(socket.into())
+		}
+		_ => TcpListener::bind((host, port)),
+	}
 }
 
-pub fn get_free_port(host: &str, port: u16) -> u16 {
-	let mut port = port;
+/// Binds the first available port, starting from the given one,
+/// the listener is kept so no other process can take it meanwhile
+pub fn bind_free_port(host: &str, port: u16) -> Result<TcpListener> {
+	for port in port..=u16::MAX {
+		if let Ok(listener) = bind(host, port) {
+			return Ok(listener);
+		}
+	}
+
+	Err(Error::new(
+		ErrorKind::AddrInUse,
+		format!("No free port available on {host}"),
+	))
+}
 
-	while !is_port_free(host, port) {
-		port += 1;
+/// Returns the session token from the given file, or from the environment
+/// if there is no file, `None` means a random token should be used
+pub fn read_token(path: Option<&Path>) -> Result<Option<String>> {
+	let token = if let Some(path) = path {
+		#[cfg(unix)]
+		{
+			use std::os::unix::fs::PermissionsExt;
 
-		// This should never happen, but just in case
-		if port == 65535 {
-			break;
+			if fs::metadata(path)?.permissions().mode() & 0o004 != 0 {
+				return Err(Error::new(
+					ErrorKind::PermissionDenied,
+					format!(
+						"Token file {} is readable by other users, restrict it with `chmod o-r`",
+						path.display()
+					),
+				));
+			}
 		}
+
+		fs::read_to_string(path)
+			.map_err(|err| Error::new(err.kind(), format!("Failed to read token file {}: {err}", path.display())))?
+	} else if let Ok(token) = env::var(TOKEN_VAR) {
+		token
+	} else {
+		return Ok(None);
+	};
+
+	let token = token.trim_end_matches(['\r', '\n']);
+
+	// The token is sent in a header, so it has to be a valid header value
+	if token.is_empty() || !token.chars().all(|char| char.is_ascii_graphic()) {
+		return Err(Error::new(
+			ErrorKind::InvalidInput,
+			"Session token has to be a non-empty string of printable ASCII characters",
+		));
 	}
 
-	port
+	Ok(Some(token.to_owned()))
+}
+
+/// Strips brackets from IPv6 literals (`[::1]` -> `::1`), so
+/// the host can be used for binding and formatted consistently
+pub fn normalize_host(host: &str) -> String {
+	host.strip_prefix('[')
+		.and_then(|host| host.strip_suffix(']'))
+		.unwrap_or(host)
+		.to_owned()
 }
 
-pub fn format_address(host: &str, port: u16) -> String {
-	format!("http://{host}:{port}")
+pub fn format_address(host: &str, port: u16, tls: bool) -> String {
+	let scheme = if tls { "https" } else { "http" };
+	let host = normalize_host(host);
+
+	if host.parse::<Ipv6Addr>().is_ok() {
+		format!("{scheme}://[{host}]:{port}")
+	} else {
+		format!("{scheme}://{host}:{port}")
+	}
 }
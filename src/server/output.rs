@@ -0,0 +1,44 @@
+use actix_msgpack::MsgPack;
+use actix_web::{post, web::Data, HttpResponse, Responder};
+use log::trace;
+use serde::Deserialize;
+use std::sync::Arc;
+
+use super::exec::{Output, OutputChunk, PENDING};
+use crate::core::Core;
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct Request {
+	client_id: u32,
+	id: String,
+	output: Vec<Output>,
+	done: bool,
+}
+
+#[post("/output")]
+async fn main(request: MsgPack<Request>, core: Data<Arc<Core>>) -> impl Responder {
+	trace!("Received request: output");
+
+	let request = request.0;
+
+	if !core.queue().is_subscribed(request.client_id) {
+		return HttpResponse::Unauthorized().body("Not subscribed");
+	}
+
+	let pending = PENDING.lock().unwrap();
+
+	let Some(sender) = pending.get(&request.id) else {
+		return HttpResponse::NotFound().body("No execution is waiting for this output");
+	};
+
+	let chunk = OutputChunk {
+		output: request.output,
+		done: request.done,
+	};
+
+	match sender.send(chunk) {
+		Ok(()) => HttpResponse::Ok().body("Output received successfully"),
+		Err(err) => HttpResponse::InternalServerError().body(err.to_string()),
+	}
+}
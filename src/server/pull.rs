@@ -0,0 +1,31 @@
+use actix_msgpack::MsgPack;
+use actix_web::{post, web::Data, HttpResponse, Responder};
+use log::trace;
+use serde::Deserialize;
+use std::sync::Arc;
+
+use crate::{core::Core, server};
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct Request {
+	paths: Vec<String>,
+}
+
+#[post("/pull")]
+async fn main(request: MsgPack<Request>, core: Data<Arc<Core>>) -> impl Responder {
+	trace!("Received request: pull");
+
+	let queue = core.queue();
+
+	if queue.get_first_non_internal_listener_name().is_none() {
+		return HttpResponse::NotFound().body("No Roblox Studio client is connected");
+	}
+
+	let pushed = queue.push(server::PullInstances { paths: request.0.paths }, None);
+
+	match pushed {
+		Ok(()) => HttpResponse::Ok().body("Pull requested successfully"),
+		Err(err) => HttpResponse::InternalServerError().body(err.to_string()),
+	}
+}
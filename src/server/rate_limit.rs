@@ -0,0 +1,71 @@
+use actix_web::{
+	body::MessageBody,
+	dev::{ServiceRequest, ServiceResponse},
+	error::ErrorTooManyRequests,
+	middleware::Next,
+	web::Data,
+	Error,
+};
+use log::warn;
+use std::{
+	collections::HashMap,
+	net::IpAddr,
+	sync::Mutex,
+	time::{Duration, Instant},
+};
+
+use crate::lock;
+
+const WINDOW: Duration = Duration::from_secs(1);
+
+/// Fixed window, per IP address request counter
+pub struct RateLimiter {
+	limit: u32,
+	clients: Mutex<HashMap<IpAddr, (Instant, u32)>>,
+}
+
+impl RateLimiter {
+	pub fn new(limit: u32) -> Self {
+		Self {
+			limit,
+			clients: Mutex::new(HashMap::new()),
+		}
+	}
+
+	/// Counts a request from the given address and returns
+	/// whether it still fits in the current window
+	pub fn check(&self, ip: IpAddr) -> bool {
+		let now = Instant::now();
+		let mut clients = lock!(self.clients);
+
+		// Forget idle clients so the map does not grow indefinitely
+		if clients.len() > 1024 {
+			clients.retain(|_, (start, _)| now.duration_since(*start) < WINDOW);
+		}
+
+		let (start, count) = clients.entry(ip).or_insert((now, 0));
+
+		if now.duration_since(*start) >= WINDOW {
+			*start = now;
+			*count = 0;
+		}
+
+		*count += 1;
+		*count <= self.limit
+	}
+}
+
+/// Rejects requests exceeding `rate_limit` setting with `429 Too Many Requests`
+pub async fn middleware(
+	request: ServiceRequest,
+	next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+	if let (Some(limiter), Some(peer)) = (request.app_data::<Data<RateLimiter>>(), request.peer_addr()) {
+		if limiter.limit > 0 && !limiter.check(peer.ip()) {
+			warn!("Rate limit exceeded by {}, rejecting {}", peer.ip(), request.path());
+			return Err(ErrorTooManyRequests("Rate limit exceeded"));
+		}
+	}
+
+	next.call(request).await
+}
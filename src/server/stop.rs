@@ -1,15 +1,25 @@
-use actix_web::{post, HttpResponse, Responder};
-use log::{info, trace};
-use std::process;
+use actix_web::{post, web::Data, HttpRequest, HttpResponse, Responder};
+use log::{info, trace, warn};
+use std::{thread, time::Duration};
 
-use crate::util;
+use crate::{processes, server::ServerState};
 
 #[post("/stop")]
-async fn main() -> impl Responder {
+async fn main(request: HttpRequest, state: Data<ServerState>) -> impl Responder {
 	trace!("Received request: stop");
+
+	if !state.is_authorized(&request) {
+		warn!("Rejected unauthorized stop request from: {:?}", request.peer_addr());
+		return HttpResponse::Unauthorized().body("Missing or invalid session token");
+	}
+
 	info!("Stopping Argon!");
 
-	util::kill_process(process::id());
+	// Give the server a moment to send the response before exiting
+	thread::spawn(|| {
+		thread::sleep(Duration::from_millis(100));
+		processes::shutdown(0);
+	});
 
 	HttpResponse::Ok().body("Argon stopped successfully")
 }
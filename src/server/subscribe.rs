@@ -11,13 +11,17 @@ use crate::core::Core;
 struct Request {
 	client_id: u32,
 	name: String,
+	#[serde(default)]
+	protocol: u32,
 }
 
 #[post("/subscribe")]
 async fn main(request: MsgPack<Request>, core: Data<Arc<Core>>) -> impl Responder {
 	trace!("Received request: subscribe");
 
-	let subscribed = core.queue().subscribe(request.client_id, &request.name);
+	let subscribed = core
+		.queue()
+		.subscribe(request.client_id, &request.name, request.protocol);
 
 	if subscribed.is_ok() {
 		HttpResponse::Ok().body("Subscribed successfully")
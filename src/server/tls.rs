@@ -0,0 +1,96 @@
+use anyhow::{Context, Result};
+use log::debug;
+use rustls::{crypto::ring, ServerConfig};
+use std::{
+	fs::{self, File, OpenOptions},
+	io::{BufReader, Write},
+	path::{Path, PathBuf},
+	sync::Arc,
+};
+
+use crate::{config::Config, util};
+
+/// Loads certificate and private key configured with `tls_cert` and `tls_key`
+/// settings or generates self-signed ones in the `.vasc/tls` directory,
+/// path of the self-signed certificate is returned so clients can pin it
+pub fn load_config(config: &Config, host: &str) -> Result<(ServerConfig, Option<PathBuf>)> {
+	let (cert_path, key_path, self_signed) = if config.tls_cert.is_empty() || config.tls_key.is_empty() {
+		let (cert_path, key_path) = generate_self_signed(host)?;
+		(cert_path, key_path, true)
+	} else {
+		(PathBuf::from(&config.tls_cert), PathBuf::from(&config.tls_key), false)
+	};
+
+	let certs = rustls_pemfile::certs(&mut BufReader::new(File::open(&cert_path)?))
+		.collect::<Result<Vec<_>, _>>()
+		.with_context(|| format!("Failed to read certificate: {}", cert_path.display()))?;
+
+	let key = rustls_pemfile::private_key(&mut BufReader::new(File::open(&key_path)?))?
+		.with_context(|| format!("No private key found in: {}", key_path.display()))?;
+
+	let config = ServerConfig::builder_with_provider(Arc::new(ring::default_provider()))
+		.with_safe_default_protocol_versions()?
+		.with_no_client_auth()
+		.with_single_cert(certs, key)?;
+
+	Ok((config, self_signed.then_some(cert_path)))
+}
+
+fn generate_self_signed(host: &str) -> Result<(PathBuf, PathBuf)> {
+	let tls_dir = util::get_vasc_dir()?.join("tls");
+
+	let cert_path = tls_dir.join("cert.pem");
+	let key_path = tls_dir.join("key.pem");
+	let names_path = tls_dir.join("names");
+
+	// Certificate is reused only if it was issued for the current host
+	if cert_path.exists() && key_path.exists() {
+		let names = fs::read_to_string(&names_path).unwrap_or_default();
+
+		if names.lines().any(|name| name == host) {
+			return Ok((cert_path, key_path));
+		}
+	}
+
+	debug!("Generating self-signed certificate for {host}");
+
+	let mut names = vec![String::from("localhost"), String::from("127.0.0.1")];
+
+	if !names.iter().any(|name| name == host) {
+		names.push(host.to_owned());
+	}
+
+	let certified = rcgen::generate_simple_self_signed(names.clone())?;
+
+	fs::create_dir_all(&tls_dir)?;
+	fs::write(&cert_path, certified.cert.pem())?;
+	fs::write(&names_path, names.join("\n"))?;
+	write_private_key(&key_path, &certified.key_pair.serialize_pem())?;
+
+	Ok((cert_path, key_path))
+}
+
+/// Writes the private key so it is readable by the current user only
+fn write_private_key(path: &Path, key: &str) -> Result<()> {
+	let mut options = OpenOptions::new();
+	options.write(true).create(true).truncate(true);
+
+	#[cfg(unix)]
+	{
+		use std::os::unix::fs::OpenOptionsExt;
+		options.mode(0o600);
+	}
+
+	let mut file = options.open(path)?;
+
+	// `mode` only applies to newly created files
+	#[cfg(unix)]
+	{
+		use std::os::unix::fs::PermissionsExt;
+		file.set_permissions(fs::Permissions::from_mode(0o600))?;
+	}
+
+	file.write_all(key.as_bytes())?;
+
+	Ok(())
+}
@@ -0,0 +1,8 @@
+use actix_web::{get, HttpResponse, Responder};
+use log::trace;
+
+#[get("/version")]
+async fn main() -> impl Responder {
+	trace!("Received request: version");
+	HttpResponse::Ok().body(env!("CARGO_PKG_VERSION"))
+}
@@ -0,0 +1,110 @@
+use actix_web::{
+	get, rt,
+	web::{self, Data, Payload, Query},
+	Error, HttpRequest, HttpResponse,
+};
+use actix_ws::AggregatedMessage;
+use log::{trace, warn};
+use serde::Deserialize;
+use std::sync::Arc;
+
+use crate::{
+	core::{changes::Changes, processor::WriteRequest, Core},
+	server::{Message, ServerState},
+};
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct Request {
+	client_id: u32,
+	name: String,
+	#[serde(default)]
+	protocol: u32,
+}
+
+/// Two-way alternative to `/read` and `/write` polling, queued messages
+/// are pushed as binary MessagePack frames and client changes are
+/// received the same way
+#[get("/ws")]
+async fn main(
+	request: HttpRequest,
+	body: Payload,
+	query: Query<Request>,
+	core: Data<Arc<Core>>,
+	state: Data<ServerState>,
+) -> Result<HttpResponse, Error> {
+	trace!("Received request: ws");
+
+	let id = query.client_id;
+	let queue = core.queue();
+
+	if queue.is_subscribed(id) {
+		return Ok(HttpResponse::BadRequest().body("Already subscribed"));
+	}
+
+	// Subscribe only after successful handshake, so a failed
+	// upgrade does not leave the client id registered forever
+	let (response, mut session, stream) = actix_ws::handle(&request, body)?;
+
+	if queue.subscribe(id, &query.name, query.protocol).is_err() {
+		session.close(None).await.ok();
+		return Ok(HttpResponse::BadRequest().body("Already subscribed"));
+	}
+
+	let mut stream = stream
+		.max_frame_size(state.max_payload_size)
+		.aggregate_continuations()
+		.max_continuation_size(state.max_payload_size);
+
+	let mut sender = session.clone();
+	let sender_queue = queue.clone();
+
+	rt::spawn(async move {
+		loop {
+			let queue = sender_queue.clone();
+
+			let message = match web::block(move || queue.get_timeout(id)).await {
+				Ok(Ok(Some(message))) => message,
+				Ok(Ok(None)) => continue,
+				_ => break,
+			};
+
+			let is_disconnect = matches!(message, Message::Disconnect(_));
+
+			let bytes = match rmp_serde::to_vec_named(&message) {
+				Ok(bytes) => bytes,
+				Err(err) => {
+					warn!("Failed to serialize WebSocket message: {err}");
+					continue;
+				}
+			};
+
+			if sender.binary(bytes).await.is_err() || is_disconnect {
+				break;
+			}
+		}
+
+		sender.close(None).await.ok();
+	});
+
+	let core = core.get_ref().clone();
+
+	rt::spawn(async move {
+		while let Some(Ok(message)) = stream.recv().await {
+			match message {
+				AggregatedMessage::Binary(bytes) => match rmp_serde::from_slice::<Changes>(&bytes) {
+					Ok(changes) => core.processor().write(WriteRequest { changes, client_id: id }),
+					Err(err) => warn!("Received invalid changes over WebSocket: {err}"),
+				},
+				AggregatedMessage::Ping(bytes) if session.pong(&bytes).await.is_err() => break,
+				AggregatedMessage::Close(_) => break,
+				_ => (),
+			}
+		}
+
+		queue.unsubscribe(id).ok();
+		session.close(None).await.ok();
+	});
+
+	Ok(response)
+}
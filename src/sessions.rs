@@ -1,27 +1,99 @@
 use anyhow::{Context, Result};
-use log::{debug, trace, warn};
+use log::{debug, warn};
+use reqwest::{blocking::Client, Certificate};
 use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, fs, process, thread};
+use std::{
+	collections::HashMap,
+	fs,
+	path::{Path, PathBuf},
+	thread,
+};
 
-use crate::util;
+use crate::{migrations, processes, project, server, util};
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 pub struct Session {
 	pub pid: u32,
 	pub host: Option<String>,
 	pub port: Option<u16>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub token: Option<String>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub project: Option<PathBuf>,
+	#[serde(default, skip_serializing_if = "std::ops::Not::not")]
+	pub tls: bool,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub cert: Option<PathBuf>,
 }
 
 impl Session {
+	pub fn new(pid: u32) -> Self {
+		Self {
+			pid,
+			host: None,
+			port: None,
+			token: None,
+			project: None,
+			tls: false,
+			cert: None,
+		}
+	}
+
+	pub fn with_address(mut self, host: &str, port: u16) -> Self {
+		self.host = Some(host.to_owned());
+		self.port = Some(port);
+		self
+	}
+
+	pub fn with_token(mut self, token: &str) -> Self {
+		self.token = Some(token.to_owned());
+		self
+	}
+
+	pub fn with_project(mut self, project: &Path) -> Self {
+		self.project = Some(project.to_owned());
+		self
+	}
+
+	pub fn with_tls(mut self, tls: bool) -> Self {
+		self.tls = tls;
+		self
+	}
+
+	/// Self-signed certificate of the server, trusted only by this session's client
+	pub fn with_cert(mut self, cert: &Path) -> Self {
+		self.cert = Some(cert.to_owned());
+		self
+	}
+
 	pub fn get_address(&self) -> Option<String> {
 		if let Some(host) = &self.host {
 			if let Some(port) = self.port {
-				return Some(format!("http://{host}:{port}"));
+				return Some(server::format_address(host, port, self.tls));
 			}
 		}
 
 		None
 	}
+
+	/// HTTP client for this session, pins the self-signed certificate
+	/// of the server if there is one and verifies certificates normally otherwise
+	pub fn client(&self) -> Client {
+		let mut builder = Client::builder();
+
+		if let (true, Some(cert)) = (self.tls, &self.cert) {
+			let certificate = fs::read(cert)
+				.map_err(anyhow::Error::from)
+				.and_then(|pem| Ok(Certificate::from_pem(&pem)?));
+
+			match certificate {
+				Ok(certificate) => builder = builder.add_root_certificate(certificate),
+				Err(err) => warn!("Failed to load session certificate {}: {}", cert.display(), err),
+			}
+		}
+
+		builder.build().unwrap_or_default()
+	}
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -34,7 +106,7 @@ fn get_sessions() -> Result<Sessions> {
 	let path = util::get_vasc_dir()?.join("sessions.toml");
 
 	if path.exists() {
-		match toml::from_str(&fs::read_to_string(&path)?) {
+		match migrations::read(&path, migrations::SESSIONS) {
 			Ok(sessions) => return Ok(sessions),
 			Err(_) => warn!("Session data file is corrupted! Creating new one.."),
 		}
@@ -45,7 +117,7 @@ fn get_sessions() -> Result<Sessions> {
 		active_sessions: HashMap::new(),
 	};
 
-	fs::write(path, toml::to_string(&sessions)?)?;
+	migrations::write(&path, &sessions, migrations::SESSIONS)?;
 
 	Ok(sessions)
 }
@@ -53,31 +125,23 @@ fn get_sessions() -> Result<Sessions> {
 fn set_sessions(sessions: &Sessions) -> Result<()> {
 	let path = util::get_vasc_dir()?.join("sessions.toml");
 
-	fs::write(path, toml::to_string(sessions)?)?;
+	migrations::write(&path, sessions, migrations::SESSIONS)?;
 
 	Ok(())
 }
 
-pub fn add(id: Option<String>, host: Option<String>, port: Option<u16>, pid: u32, run_async: bool) -> Result<()> {
+pub fn add(id: Option<String>, session: Session, run_async: bool) -> Result<()> {
 	let mut sessions = get_sessions()?;
 
-	let session = Session { host, port, pid };
 	let id = id.unwrap_or(generate_id(&sessions));
 
 	sessions.last_session.clone_from(&id);
-	sessions.active_sessions.insert(id, session.clone());
+	sessions.active_sessions.insert(id, session);
 
 	set_sessions(&sessions)?;
 
 	if !run_async {
-		ctrlc::set_handler(move || {
-			match remove(&session) {
-				Ok(()) => trace!("Session entry removed"),
-				Err(err) => warn!("Failed to remove session entry: {err}"),
-			}
-
-			process::exit(0);
-		})?;
+		ctrlc::set_handler(|| processes::shutdown(0))?;
 	}
 
 	// Schedule manual cleanup of old sessions
@@ -91,17 +155,31 @@ pub fn add(id: Option<String>, host: Option<String>, port: Option<u16>, pid: u32
 	Ok(())
 }
 
+/// Returns session matching provided filters. When no filter is given,
+/// session serving the project in the current directory is preferred
+/// over the last started one
 pub fn get(id: Option<String>, host: Option<String>, port: Option<u16>) -> Result<Option<Session>> {
 	let sessions = get_sessions()?;
 
 	if id.is_none() && host.is_none() && port.is_none() {
+		if let Ok(project) = project::resolve(PathBuf::new()) {
+			if let Some(session) = find_by_project(&sessions, &project) {
+				return Ok(Some(session));
+			}
+		}
+
 		return Ok(sessions.active_sessions.get(&sessions.last_session).cloned());
 	} else if let Some(id) = id {
 		return Ok(sessions.active_sessions.get(&id).cloned());
 	}
 
+	let host = host.map(|host| server::normalize_host(&host));
+
 	for (_, session) in sessions.active_sessions {
-		if session.host == host || session.port == port {
+		let host_matches = host.is_none() || session.host == host;
+		let port_matches = port.is_none() || session.port == port;
+
+		if host_matches && port_matches {
 			return Ok(Some(session));
 		}
 	}
@@ -109,6 +187,10 @@ pub fn get(id: Option<String>, host: Option<String>, port: Option<u16>) -> Resul
 	Ok(None)
 }
 
+pub fn get_by_project(project: &Path) -> Result<Option<Session>> {
+	Ok(find_by_project(&get_sessions()?, project))
+}
+
 pub fn get_multiple(ids: &Vec<String>) -> Result<HashMap<String, Session>> {
 	let sessions = get_sessions()?;
 
@@ -151,6 +233,41 @@ pub fn remove(session: &Session) -> Result<()> {
 	Ok(())
 }
 
+/// Removes all sessions started by the process with the given `pid`
+pub fn remove_by_pid(pid: u32) -> Result<()> {
+	let mut sessions = get_sessions()?;
+
+	sessions.active_sessions.retain(|_, session| session.pid != pid);
+
+	if !sessions.active_sessions.contains_key(&sessions.last_session) {
+		sessions.last_session = sessions.active_sessions.keys().next().cloned().unwrap_or_default();
+	}
+
+	set_sessions(&sessions)
+}
+
+/// Removes sessions whose process is no longer running, returns their number
+pub fn remove_stale() -> Result<usize> {
+	let mut sessions = get_sessions()?;
+	let count = sessions.active_sessions.len();
+
+	sessions
+		.active_sessions
+		.retain(|_, session| util::process_exists(session.pid));
+
+	let removed = count - sessions.active_sessions.len();
+
+	if removed > 0 {
+		if !sessions.active_sessions.contains_key(&sessions.last_session) {
+			sessions.last_session = sessions.active_sessions.keys().next().cloned().unwrap_or_default();
+		}
+
+		set_sessions(&sessions)?;
+	}
+
+	Ok(removed)
+}
+
 pub fn remove_multiple(ids: &Vec<String>) -> Result<()> {
 	let mut sessions = get_sessions()?;
 
@@ -193,6 +310,14 @@ fn cleanup(mut sessions: Sessions) -> Result<()> {
 	Ok(())
 }
 
+fn find_by_project(sessions: &Sessions, project: &Path) -> Option<Session> {
+	sessions
+		.active_sessions
+		.values()
+		.find(|session| session.project.as_deref() == Some(project))
+		.cloned()
+}
+
 fn generate_id(sessions: &Sessions) -> String {
 	let mut index = 0;
 
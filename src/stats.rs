@@ -5,13 +5,12 @@ use reqwest::blocking::Client;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 use std::{
-	fs,
 	sync::RwLock,
 	thread,
 	time::{Duration, SystemTime},
 };
 
-use crate::util;
+use crate::{migrations, util};
 
 lazy_static! {
 	static ref TRACKER: RwLock<StatTracker> = RwLock::new(StatTracker::default());
@@ -88,7 +87,7 @@ fn get_tracker() -> Result<StatTracker> {
 	let path = util::get_vasc_dir()?.join("stats.toml");
 
 	if path.exists() {
-		match toml::from_str(&fs::read_to_string(&path)?) {
+		match migrations::read(&path, migrations::STATS) {
 			Ok(tracker) => return Ok(tracker),
 			Err(_) => warn!("Stat tracker file is corrupted! Creating new one.."),
 		}
@@ -96,7 +95,7 @@ fn get_tracker() -> Result<StatTracker> {
 
 	let tracker = StatTracker::default();
 
-	fs::write(path, toml::to_string(&tracker)?)?;
+	migrations::write(&path, &tracker, migrations::STATS)?;
 
 	Ok(tracker)
 }
@@ -104,7 +103,7 @@ fn get_tracker() -> Result<StatTracker> {
 fn set_tracker(tracker: &StatTracker) -> Result<()> {
 	let path = util::get_vasc_dir()?.join("stats.toml");
 
-	fs::write(path, toml::to_string(tracker)?)?;
+	migrations::write(&path, tracker, migrations::STATS)?;
 
 	Ok(())
 }
@@ -0,0 +1,165 @@
+use anyhow::Result;
+use log::{debug, warn};
+use reqwest::blocking::Client;
+use serde::{Deserialize, Serialize};
+use std::{
+	sync::atomic::{AtomicUsize, Ordering},
+	time::{Duration, SystemTime},
+};
+
+use crate::{migrations, util};
+
+/// Number of events collected before they are uploaded
+const BATCH_SIZE: usize = 20;
+
+/// Events older than this are uploaded even if batch is not full
+const MAX_BATCH_AGE: Duration = Duration::from_secs(86400);
+
+/// Oldest events are dropped once there is more of them, e.g. when offline
+const MAX_PENDING: usize = BATCH_SIZE * 5;
+
+/// Upload request is abandoned after this time so commands are not held up
+const UPLOAD_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Time to wait before next upload attempt after a failed one
+const RETRY_DELAY: Duration = Duration::from_secs(3600);
+
+/// Endpoint that events are uploaded to, set by the release workflow,
+/// telemetry is disabled in builds without it (e.g. local ones)
+const TELEMETRY_URL: Option<&str> = option_env!("VASC_TELEMETRY_URL");
+
+static PROJECT_SIZE: AtomicUsize = AtomicUsize::new(0);
+
+/// Single anonymous event, this is everything that gets sent
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Event {
+	pub command: String,
+	pub duration_ms: u64,
+	pub success: bool,
+	pub project_size: String,
+	pub version: String,
+	pub os: String,
+	pub timestamp: SystemTime,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Telemetry {
+	/// Events waiting to be uploaded
+	#[serde(default)]
+	pub pending: Vec<Event>,
+	/// Last batch of events that was uploaded
+	#[serde(default)]
+	pub sent: Vec<Event>,
+	/// Uploads are not attempted before this time after a failure
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub retry_at: Option<SystemTime>,
+}
+
+pub fn get_telemetry() -> Result<Telemetry> {
+	let path = util::get_vasc_dir()?.join("telemetry.toml");
+
+	if path.exists() {
+		match migrations::read(&path, migrations::TELEMETRY) {
+			Ok(telemetry) => return Ok(telemetry),
+			Err(_) => warn!("Telemetry file is corrupted! Creating new one.."),
+		}
+	}
+
+	let telemetry = Telemetry::default();
+
+	migrations::write(&path, &telemetry, migrations::TELEMETRY)?;
+
+	Ok(telemetry)
+}
+
+fn set_telemetry(telemetry: &Telemetry) -> Result<()> {
+	let path = util::get_vasc_dir()?.join("telemetry.toml");
+
+	migrations::write(&path, telemetry, migrations::TELEMETRY)?;
+
+	Ok(())
+}
+
+/// Remembers the number of instances in the served or built project,
+/// only its rough bucket is ever recorded
+pub fn set_project_size(size: usize) {
+	PROJECT_SIZE.store(size, Ordering::Relaxed);
+}
+
+fn project_size_bucket() -> String {
+	let bucket = match PROJECT_SIZE.load(Ordering::Relaxed) {
+		0 => "none",
+		1..=100 => "small",
+		101..=1000 => "medium",
+		1001..=10000 => "large",
+		_ => "huge",
+	};
+
+	bucket.to_owned()
+}
+
+fn get_url() -> Option<&'static str> {
+	TELEMETRY_URL.filter(|url| !url.is_empty())
+}
+
+/// Whether this build can upload events at all
+pub fn is_available() -> bool {
+	get_url().is_some()
+}
+
+/// Records command result and uploads pending events once batch is full
+pub fn record(command: &str, duration: Duration, success: bool) -> Result<()> {
+	let Some(url) = get_url() else {
+		debug!("This Vasc build has no `VASC_TELEMETRY_URL` set, telemetry will not be recorded");
+		return Ok(());
+	};
+
+	let mut telemetry = get_telemetry()?;
+
+	telemetry.pending.push(Event {
+		command: command.to_owned(),
+		duration_ms: duration.as_millis() as u64,
+		success,
+		project_size: project_size_bucket(),
+		version: env!("CARGO_PKG_VERSION").to_owned(),
+		os: std::env::consts::OS.to_owned(),
+		timestamp: SystemTime::now(),
+	});
+
+	let oldest = telemetry.pending.first().map(|event| event.timestamp);
+	let is_stale = oldest.is_some_and(|time| time.elapsed().unwrap_or_default() > MAX_BATCH_AGE);
+
+	let can_retry = telemetry.retry_at.is_none_or(|time| SystemTime::now() >= time);
+
+	if (telemetry.pending.len() >= BATCH_SIZE || is_stale) && can_retry {
+		let result = Client::builder()
+			.timeout(UPLOAD_TIMEOUT)
+			.build()
+			.and_then(|client| client.post(url).json(&telemetry.pending).send())
+			.and_then(|response| response.error_for_status());
+
+		match result {
+			Ok(_) => {
+				telemetry.sent = std::mem::take(&mut telemetry.pending);
+				telemetry.retry_at = None;
+
+				debug!("Uploaded {} telemetry events", telemetry.sent.len());
+			}
+			Err(err) => {
+				telemetry.retry_at = Some(SystemTime::now() + RETRY_DELAY);
+				debug!("Failed to upload telemetry events, retrying in an hour: {err}");
+			}
+		}
+	}
+
+	if telemetry.pending.len() > MAX_PENDING {
+		let excess = telemetry.pending.len() - MAX_PENDING;
+		telemetry.pending.drain(..excess);
+	}
+
+	set_telemetry(&telemetry)
+}
+
+pub fn clear() -> Result<()> {
+	set_telemetry(&Telemetry::default())
+}
@@ -0,0 +1,543 @@
+use anyhow::{bail, Result};
+use log::{debug, warn};
+use serde::{Deserialize, Serialize};
+use std::{
+	collections::HashMap,
+	fs::{self, File},
+	io::{self, Write},
+	path::{Component, Path, PathBuf},
+	process::Command,
+};
+use zip::{write::SimpleFileOptions, ZipArchive, ZipWriter};
+
+use crate::{
+	ext::PathExt,
+	logger,
+	program::{Program, ProgramName},
+	util, vasc_info, vasc_warn,
+};
+
+/// Name of the metadata file stored in every template directory
+pub const MANIFEST_NAME: &str = "template.toml";
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TemplateManifest {
+	pub name: String,
+	#[serde(default)]
+	pub description: String,
+	#[serde(default)]
+	pub version: String,
+	#[serde(default)]
+	pub source: String,
+	/// Git revision the template is pinned to
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub rev: Option<String>,
+	/// Shell commands executed in the project directory after scaffolding
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub hooks: Vec<String>,
+	/// Example files (relative to the template) that are skipped
+	/// when the project is initialized without examples
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub examples: Vec<String>,
+	/// Files (relative to the template) in which `{{name}}` placeholders
+	/// are substituted, all other files are copied unchanged
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub templated: Vec<String>,
+}
+
+impl TemplateManifest {
+	pub fn new(name: &str, description: &str, version: &str, source: &str) -> Self {
+		Self {
+			name: name.to_owned(),
+			description: description.to_owned(),
+			version: version.to_owned(),
+			source: source.to_owned(),
+			rev: None,
+			hooks: Vec::new(),
+			examples: Vec::new(),
+			templated: Vec::new(),
+		}
+	}
+
+	pub fn with_examples(mut self, examples: &[&str]) -> Self {
+		self.examples = examples.iter().map(|example| (*example).to_owned()).collect();
+		self
+	}
+
+	pub fn with_rev(mut self, rev: Option<&str>) -> Self {
+		self.rev = rev.map(|rev| rev.to_owned());
+		self
+	}
+
+	pub fn is_bundled(&self) -> bool {
+		self.source == "bundled"
+	}
+
+	pub fn is_git(&self) -> bool {
+		self.source.starts_with("https://")
+			|| self.source.starts_with("http://")
+			|| self.source.starts_with("git@")
+			|| self.source.starts_with("ssh://")
+			|| self.source.ends_with(".git")
+	}
+}
+
+/// Values of `{{name}}` placeholders substituted in template files,
+/// missing ones are asked for in interactive mode or left empty and then cached
+#[derive(Debug, Clone, Default)]
+pub struct Variables {
+	values: HashMap<String, String>,
+	files: Vec<PathBuf>,
+	interactive: bool,
+}
+
+impl Variables {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Allows asking for values of missing variables
+	pub fn with_interactive(mut self, interactive: bool) -> Self {
+		self.interactive = interactive;
+		self
+	}
+
+	/// Sets variable unless it was already provided
+	pub fn with(mut self, name: &str, value: &str) -> Self {
+		self.values.entry(name.to_owned()).or_insert(value.to_owned());
+		self
+	}
+
+	/// Parses `name=value` pairs, usually coming from `--var` arguments
+	pub fn with_pairs(mut self, pairs: &[String]) -> Result<Self> {
+		for pair in pairs {
+			let Some((name, value)) = pair.split_once('=') else {
+				bail!("Invalid variable: {pair}, expected `name=value`");
+			};
+
+			self.values.insert(name.trim().to_owned(), value.to_owned());
+		}
+
+		Ok(self)
+	}
+
+	/// Sets files listed in the `templated` field of the template manifest
+	pub fn set_files(&mut self, files: Vec<PathBuf>) {
+		self.files = files;
+	}
+
+	/// Whether placeholders should be substituted in the given template file
+	pub fn applies_to(&self, path: &Path) -> bool {
+		self.files.iter().any(|file| file == path)
+	}
+
+	/// Substitutes placeholders only if the template file is listed in its manifest,
+	/// so sources that contain `{{` on their own (e.g. Luau tables) stay untouched
+	pub fn substitute_file(&mut self, path: &Path, contents: String) -> String {
+		if self.applies_to(path) {
+			self.substitute(&contents)
+		} else {
+			contents
+		}
+	}
+
+	pub fn substitute(&mut self, contents: &str) -> String {
+		let mut result = String::with_capacity(contents.len());
+		let mut rest = contents;
+
+		while let Some(start) = rest.find("{{") {
+			let Some(end) = rest[start..].find("}}") else {
+				break;
+			};
+
+			let name = rest[start + 2..start + end].trim();
+
+			result.push_str(&rest[..start]);
+
+			if !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+				result.push_str(&self.get(name));
+			} else {
+				result.push_str(&rest[start..start + end + 2]);
+			}
+
+			rest = &rest[start + end + 2..];
+		}
+
+		result.push_str(rest);
+		result
+	}
+
+	fn get(&mut self, name: &str) -> String {
+		let interactive = self.interactive;
+
+		self.values
+			.entry(name.to_owned())
+			.or_insert_with(|| {
+				if interactive {
+					logger::input(&format!("Value for template variable `{name}`"), "")
+				} else {
+					vasc_warn!("Template variable `{}` is not set, leaving it empty", name);
+					String::new()
+				}
+			})
+			.clone()
+	}
+}
+
+pub fn get_templates_dir() -> Result<PathBuf> {
+	Ok(util::get_vasc_dir()?.join("templates"))
+}
+
+/// Returns directory of the template with the given name, names that are not
+/// a single path component (e.g. `..` or absolute paths) are rejected so they
+/// can't point outside of the templates directory
+pub fn get_template_dir(name: &str) -> Result<PathBuf> {
+	let mut components = Path::new(name).components();
+
+	match (components.next(), components.next()) {
+		(Some(Component::Normal(_)), None) => Ok(get_templates_dir()?.join(name)),
+		_ => bail!("Invalid template name: {name}"),
+	}
+}
+
+pub fn get_manifest(template_dir: &Path) -> Result<Option<TemplateManifest>> {
+	let path = template_dir.join(MANIFEST_NAME);
+
+	if !path.exists() {
+		return Ok(None);
+	}
+
+	match toml::from_str(&fs::read_to_string(&path)?) {
+		Ok(manifest) => Ok(Some(manifest)),
+		Err(_) => {
+			warn!("Template manifest {} is corrupted!", path.display());
+			Ok(None)
+		}
+	}
+}
+
+pub fn set_manifest(template_dir: &Path, manifest: &TemplateManifest) -> Result<()> {
+	fs::write(template_dir.join(MANIFEST_NAME), toml::to_string(manifest)?)?;
+	Ok(())
+}
+
+/// Returns manifests of all installed templates, templates
+/// without one get a manifest with their directory name only
+pub fn get_all() -> Result<Vec<TemplateManifest>> {
+	let templates_dir = get_templates_dir()?;
+	let mut templates = vec![];
+
+	if !templates_dir.exists() {
+		return Ok(templates);
+	}
+
+	for entry in fs::read_dir(templates_dir)? {
+		let path = entry?.path();
+
+		if !path.is_dir() {
+			continue;
+		}
+
+		let manifest =
+			get_manifest(&path)?.unwrap_or_else(|| TemplateManifest::new(path.get_name(), "", "", "unknown"));
+
+		templates.push(manifest);
+	}
+
+	templates.sort_by(|a, b| a.name.cmp(&b.name));
+
+	Ok(templates)
+}
+
+pub fn get(name: &str) -> Result<Option<(PathBuf, TemplateManifest)>> {
+	let path = get_template_dir(name)?;
+
+	if !path.is_dir() {
+		return Ok(None);
+	}
+
+	let manifest = get_manifest(&path)?.unwrap_or_else(|| TemplateManifest::new(name, "", "", "unknown"));
+
+	Ok(Some((path, manifest)))
+}
+
+pub fn remove(name: &str) -> Result<()> {
+	let Some((path, manifest)) = get(name)? else {
+		bail!("Template {name} does not exist");
+	};
+
+	// Bundled templates would be reinstalled by the installer on the next run anyway
+	if manifest.is_bundled() {
+		bail!("Template {name} is bundled with Vasc and can't be removed");
+	}
+
+	fs::remove_dir_all(path)?;
+
+	Ok(())
+}
+
+/// Packages project directory into a zip archive that can be installed with `add_from_archive`
+pub fn export(project_dir: &Path, output: &Path) -> Result<TemplateManifest> {
+	let mut writer = ZipWriter::new(File::create(output)?);
+
+	// Output may be placed inside the exported directory
+	let project_dir = project_dir.canonicalize()?;
+	let output = output.canonicalize()?;
+
+	let name = project_dir.get_name();
+	let manifest = get_manifest(&project_dir)?.unwrap_or_else(|| TemplateManifest::new(name, "", "1.0.0", "local"));
+
+	write_archive(&mut writer, &project_dir, &project_dir, &output)?;
+
+	writer.start_file(MANIFEST_NAME, SimpleFileOptions::default())?;
+	writer.write_all(toml::to_string(&manifest)?.as_bytes())?;
+	writer.finish()?;
+
+	Ok(manifest)
+}
+
+/// Installs template from an archive created with `export`
+pub fn add_from_archive(path: &Path) -> Result<TemplateManifest> {
+	let mut archive = ZipArchive::new(File::open(path)?)?;
+
+	let manifest = match archive.by_name(MANIFEST_NAME) {
+		Ok(file) => toml::from_str(&io::read_to_string(file)?)?,
+		Err(_) => TemplateManifest::new(path.get_stem(), "", "", "local"),
+	};
+
+	let template_dir = get_template_dir(&manifest.name)?;
+
+	if template_dir.exists() {
+		fs::remove_dir_all(&template_dir)?;
+	}
+
+	archive.extract(&template_dir)?;
+
+	Ok(manifest)
+}
+
+/// Runs template post-init hooks after user confirms them, hooks can run
+/// arbitrary commands so they are never accepted without an explicit answer
+pub fn run_hooks(manifest: &TemplateManifest, dir: &Path) -> Result<()> {
+	if manifest.hooks.is_empty() {
+		return Ok(());
+	}
+
+	let prompt = format!(
+		"Template {} wants to run: {}. Do you want to continue?",
+		manifest.name,
+		manifest.hooks.join(", ")
+	);
+
+	if !logger::prompt(&prompt, false) {
+		if util::env_yes() {
+			vasc_warn!(
+				"Skipped hooks of template {}, they have to be confirmed manually",
+				manifest.name
+			);
+		}
+
+		return Ok(());
+	}
+
+	for hook in &manifest.hooks {
+		vasc_info!("Running: {}", hook);
+
+		#[cfg(not(target_os = "windows"))]
+		let status = Command::new("sh").arg("-c").arg(hook).current_dir(dir).status();
+
+		#[cfg(target_os = "windows")]
+		let status = Command::new("cmd").arg("/C").arg(hook).current_dir(dir).status();
+
+		match status {
+			Ok(status) if status.success() => debug!("Hook `{hook}` finished successfully"),
+			Ok(status) => vasc_warn!("Hook `{}` failed with {}", hook, status),
+			Err(err) => vasc_warn!("Failed to run hook `{}`: {}", hook, err),
+		}
+	}
+
+	Ok(())
+}
+
+/// Clones repository into the sources cache and installs all templates it contains,
+/// repository can be a single template or a set of templates in its subdirectories
+pub fn add_from_git(url: &str, rev: Option<&str>) -> Result<Vec<TemplateManifest>> {
+	let repo_name = url
+		.trim_end_matches('/')
+		.trim_end_matches(".git")
+		.rsplit(['/', ':'])
+		.next()
+		.unwrap_or_default()
+		.to_owned();
+
+	if repo_name.is_empty() || get_template_dir(&repo_name).is_err() {
+		bail!("Invalid repository URL: {url}");
+	}
+
+	let source_dir = util::get_vasc_dir()?.join("template-sources").join(&repo_name);
+
+	if source_dir.exists() {
+		fs::remove_dir_all(&source_dir)?;
+	}
+
+	fs::create_dir_all(source_dir.get_parent())?;
+
+	git(source_dir.get_parent(), &["clone", "--", url, &repo_name])?;
+
+	if let Some(rev) = rev {
+		// Revision can't be passed after `--` as that would make it a path
+		if rev.starts_with('-') {
+			bail!("Invalid revision: {rev}");
+		}
+
+		git(&source_dir, &["checkout", rev, "--"])?;
+	}
+
+	let mut templates = vec![];
+
+	if is_template(&source_dir) {
+		templates.push((source_dir.clone(), repo_name));
+	} else {
+		for entry in fs::read_dir(&source_dir)? {
+			let path = entry?.path();
+
+			if path.is_dir() && is_template(&path) {
+				templates.push((path.clone(), path.get_name().to_owned()));
+			}
+		}
+	}
+
+	if templates.is_empty() {
+		bail!("Repository {url} does not contain any templates");
+	}
+
+	let mut installed = vec![];
+
+	for (path, name) in templates {
+		let manifest = get_manifest(&path)?.unwrap_or_else(|| TemplateManifest::new(&name, "", "", url));
+
+		let manifest = TemplateManifest {
+			source: url.to_owned(),
+			..manifest
+		}
+		.with_rev(rev);
+
+		let template_dir = get_template_dir(&manifest.name)?;
+
+		if template_dir.exists() {
+			fs::remove_dir_all(&template_dir)?;
+		}
+
+		copy_template(&path, &template_dir)?;
+		set_manifest(&template_dir, &manifest)?;
+
+		debug!("Installed template {} from {url}", manifest.name);
+
+		installed.push(manifest);
+	}
+
+	Ok(installed)
+}
+
+/// Fetches git based templates again, respecting their pinned revisions
+pub fn update(name: Option<&str>) -> Result<Vec<TemplateManifest>> {
+	let mut sources: Vec<(String, Option<String>)> = vec![];
+
+	for manifest in get_all()? {
+		if !manifest.is_git() || name.is_some_and(|name| name != manifest.name) {
+			continue;
+		}
+
+		let source = (manifest.source, manifest.rev);
+
+		if !sources.contains(&source) {
+			sources.push(source);
+		}
+	}
+
+	let mut updated = vec![];
+
+	for (url, rev) in sources {
+		updated.extend(add_from_git(&url, rev.as_deref())?);
+	}
+
+	Ok(updated)
+}
+
+fn is_template(path: &Path) -> bool {
+	path.join(MANIFEST_NAME).exists() || path.join("project.json").exists()
+}
+
+fn copy_template(from: &Path, to: &Path) -> Result<()> {
+	fs::create_dir_all(to)?;
+
+	for entry in fs::read_dir(from)? {
+		let path = entry?.path();
+		let name = path.get_name();
+
+		if name == ".git" {
+			continue;
+		}
+
+		if path.is_dir() {
+			copy_template(&path, &to.join(name))?;
+		} else {
+			fs::copy(&path, to.join(name))?;
+		}
+	}
+
+	Ok(())
+}
+
+fn write_archive(writer: &mut ZipWriter<File>, root: &Path, dir: &Path, output: &Path) -> Result<()> {
+	for entry in fs::read_dir(dir)? {
+		let path = entry?.path();
+
+		if path == output || matches!(path.get_name(), ".git" | "node_modules" | MANIFEST_NAME) {
+			continue;
+		}
+
+		// Installed packages, sourcemap and build outputs are generated from the project
+		if dir == root
+			&& (matches!(
+				path.get_name(),
+				"Packages" | "ServerPackages" | "DevPackages" | "sourcemap.json"
+			) || matches!(path.get_ext(), "rbxl" | "rbxlx" | "rbxm" | "rbxmx"))
+		{
+			continue;
+		}
+
+		let name = path.strip_prefix(root)?.to_string_lossy().replace('\\', "/");
+
+		// `vasc init` expects the main project file of a template to be named `project.json`
+		let name = if name == "default.project.json" && !root.join("project.json").exists() {
+			String::from("project.json")
+		} else {
+			name
+		};
+
+		if path.is_dir() {
+			writer.add_directory(name, SimpleFileOptions::default())?;
+			write_archive(writer, root, &path, output)?;
+		} else {
+			writer.start_file(name, SimpleFileOptions::default())?;
+			io::copy(&mut File::open(&path)?, writer)?;
+		}
+	}
+
+	Ok(())
+}
+
+fn git(dir: &Path, args: &[&str]) -> Result<()> {
+	let output = Program::new(ProgramName::Git)
+		.message("Failed to fetch template repository")
+		.current_dir(dir)
+		.args(args.iter().copied())
+		.output()?;
+
+	match output {
+		Some(output) if output.status.success() => Ok(()),
+		Some(output) => bail!("{}", String::from_utf8_lossy(&output.stderr).trim()),
+		None => bail!("Git is required to install templates from repositories"),
+	}
+}
@@ -1,7 +1,12 @@
 use anyhow::Result;
 use colored::Colorize;
 use log::{debug, trace, warn};
-use self_update::{backends::github::Update, cargo_crate_version, version::bump_is_greater};
+use self_update::{
+	backends::github::{ReleaseList, Update},
+	cargo_crate_version,
+	update::Release,
+	version::bump_is_greater,
+};
 use serde::{Deserialize, Serialize};
 use std::{
 	env::consts::{ARCH, OS},
@@ -12,9 +17,10 @@ use std::{
 
 use crate::{
 	constants::TEMPLATES_VERSION,
-	installer::{get_plugin_version, install_templates},
-	logger,
-	util::{self, get_plugin_path},
+	ext::PathExt,
+	installer::{self, get_plugin_version, install_templates},
+	logger, migrations, t,
+	util::{self, get_plugin_paths},
 	vasc_error, vasc_info,
 };
 
@@ -25,13 +31,16 @@ pub struct UpdateStatus {
 	pub last_checked: SystemTime,
 	pub plugin_version: String,
 	pub templates_version: u8,
+	/// Version of the beta plugin channel, `None` if it's not installed
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub beta_plugin_version: Option<String>,
 }
 
 pub fn get_status() -> Result<UpdateStatus> {
 	let path = util::get_vasc_dir()?.join("update.toml");
 
 	if path.exists() {
-		match toml::from_str(&fs::read_to_string(&path)?) {
+		match migrations::read(&path, migrations::UPDATE_STATUS) {
 			Ok(status) => return Ok(status),
 			Err(_) => warn!("Update status file is corrupted! Creating new one.."),
 		}
@@ -41,9 +50,10 @@ pub fn get_status() -> Result<UpdateStatus> {
 		last_checked: SystemTime::UNIX_EPOCH,
 		plugin_version: get_plugin_version(),
 		templates_version: TEMPLATES_VERSION,
+		beta_plugin_version: None,
 	};
 
-	fs::write(path, toml::to_string(&status)?)?;
+	migrations::write(&path, &status, migrations::UPDATE_STATUS)?;
 
 	Ok(status)
 }
@@ -51,7 +61,7 @@ pub fn get_status() -> Result<UpdateStatus> {
 pub fn set_status(status: &UpdateStatus) -> Result<()> {
 	let path = util::get_vasc_dir()?.join("update.toml");
 
-	fs::write(path, toml::to_string(status)?)?;
+	migrations::write(&path, status, migrations::UPDATE_STATUS)?;
 
 	Ok(())
 }
@@ -81,27 +91,23 @@ fn update_cli(prompt: bool, force: bool) -> Result<bool> {
 	let release = update.get_latest_release()?;
 
 	if bump_is_greater(current_version, &release.version)? || force {
-		if !prompt
-			|| logger::prompt(
-				&format!(
-					"New Vasc version: {} is available! Would you like to update?",
-					release.version.bold()
-				),
-				true,
-			) {
+		if !prompt || logger::prompt(&t!("updater.cli_available", version = release.version.bold()), true) {
 			if !prompt {
-				vasc_info!("New Vasc version: {} is available! Updating..", release.version.bold());
+				vasc_info!("{}", t!("updater.cli_updating", version = release.version.bold()));
 			}
 
 			match update.update() {
 				Ok(_) => {
 					vasc_info!(
-						"CLI updated! Restart the program to apply changes. Visit {} to read the changelog",
-						"https://github.com/vadymcap/Vasc/releases".bold()
+						"{}",
+						t!(
+							"updater.cli_updated",
+							link = "https://github.com/vadymcap/Vasc/releases".bold()
+						)
 					);
 					return Ok(true);
 				}
-				Err(err) => vasc_error!("Failed to update Vasc: {}", err),
+				Err(err) => vasc_error!("{}", t!("updater.cli_failed", error = err)),
 			}
 		} else {
 			trace!("Vasc is out of date!");
@@ -116,7 +122,8 @@ fn update_cli(prompt: bool, force: bool) -> Result<bool> {
 fn update_plugin(status: &mut UpdateStatus, prompt: bool, force: bool) -> Result<bool> {
 	let style = util::get_progress_style();
 	let current_version = &status.plugin_version;
-	let plugin_path = get_plugin_path()?;
+	let plugin_paths = get_plugin_paths()?;
+	let plugin_path = &plugin_paths[0];
 
 	let update = Update::configure()
 		.repo_owner("vadymcap")
@@ -131,33 +138,31 @@ fn update_plugin(status: &mut UpdateStatus, prompt: bool, force: bool) -> Result
 	let release = update.get_latest_release()?;
 
 	if bump_is_greater(current_version, &release.version)? || force {
-		if !prompt
-			|| logger::prompt(
-				&format!(
-					"New version of Vasc plugin: {} is available! Would you like to update?",
-					release.version.bold()
-				),
-				true,
-			) {
+		if !prompt || logger::prompt(&t!("updater.plugin_available", version = release.version.bold()), true) {
 			if !prompt {
-				vasc_info!(
-					"New version of Vasc plugin: {} is available! Updating..",
-					release.version.bold()
-				);
+				vasc_info!("{}", t!("updater.plugin_updating", version = release.version.bold()));
 			}
 
 			match update.download() {
 				Ok(_) => {
+					for path in &plugin_paths[1..] {
+						fs::create_dir_all(path.get_parent())?;
+						fs::copy(plugin_path, path)?;
+					}
+
 					vasc_info!(
-						"Roblox plugin updated! Make sure you have {} setting enabled to see changes. Visit {} to read the changelog",
-						"Reload plugins on file changed".bold(),
-						"https://github.com/vadymcap/Vasc-roblox/releases".bold()
+						"{}",
+						t!(
+							"updater.plugin_updated",
+							setting = "Reload plugins on file changed".bold(),
+							link = "https://github.com/vadymcap/Vasc-roblox/releases".bold()
+						)
 					);
 
 					status.plugin_version = release.version;
 					return Ok(true);
 				}
-				Err(err) => vasc_error!("Failed to update Vasc plugin: {}", err),
+				Err(err) => vasc_error!("{}", t!("updater.plugin_failed", error = err)),
 			}
 		} else {
 			trace!("Vasc plugin is out of date!");
@@ -169,11 +174,52 @@ fn update_plugin(status: &mut UpdateStatus, prompt: bool, force: bool) -> Result
 	Ok(false)
 }
 
+/// Returns the newest pre-release of the plugin, if there is any
+pub fn get_latest_beta_release() -> Result<Option<Release>> {
+	let releases = ReleaseList::configure()
+		.repo_owner("vadymcap")
+		.repo_name("Vasc-roblox")
+		.build()?
+		.fetch()?;
+
+	Ok(releases.into_iter().find(|release| release.version.contains('-')))
+}
+
+fn update_beta_plugin(status: &mut UpdateStatus, prompt: bool, force: bool) -> Result<bool> {
+	let Some(current_version) = status.beta_plugin_version.clone() else {
+		return Ok(false);
+	};
+
+	let Some(release) = get_latest_beta_release()? else {
+		trace!("There is no beta release of Vasc plugin");
+		return Ok(false);
+	};
+
+	if bump_is_greater(&current_version, &release.version)? || force {
+		if !prompt || logger::prompt(&t!("updater.beta_available", version = release.version.bold()), true) {
+			for path in get_plugin_paths()? {
+				installer::download_beta_plugin(&release, &installer::get_beta_path(&path), true)?;
+			}
+
+			vasc_info!("{}", t!("updater.beta_updated", version = release.version.bold()));
+
+			status.beta_plugin_version = Some(release.version);
+			return Ok(true);
+		} else {
+			trace!("Vasc beta plugin is out of date!");
+		}
+	} else {
+		trace!("Vasc beta plugin is up to date!");
+	}
+
+	Ok(false)
+}
+
 fn update_templates(status: &mut UpdateStatus, prompt: bool, force: bool) -> Result<bool> {
 	if status.templates_version < TEMPLATES_VERSION || force {
-		if !prompt || logger::prompt("Default templates have changed! Would you like to update?", true) {
+		if !prompt || logger::prompt(&t!("updater.templates_changed"), true) {
 			if !prompt {
-				vasc_info!("Default templates have changed! Updating..",);
+				vasc_info!("{}", t!("updater.templates_updating"));
 			}
 
 			install_templates(true)?;
@@ -207,6 +253,7 @@ pub fn check_for_updates(plugin: bool, templates: bool, prompt: bool) -> Result<
 
 	if plugin {
 		update_plugin(&mut status, prompt, false)?;
+		update_beta_plugin(&mut status, prompt, false)?;
 	}
 
 	if templates {
@@ -233,6 +280,10 @@ pub fn manual_update(cli: bool, plugin: bool, templates: bool, force: bool) -> R
 		updated = true;
 	}
 
+	if plugin && update_beta_plugin(&mut status, false, force)? {
+		updated = true;
+	}
+
 	if templates && update_templates(&mut status, false, force)? {
 		updated = true;
 	}
@@ -1,15 +1,15 @@
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use colored::Colorize;
 use directories::UserDirs;
 use env_logger::WriteStyle;
 use json_formatter::JsonFormatter;
-use log::LevelFilter;
+use log::{trace, LevelFilter};
 use rbx_dom_weak::types::Variant;
 use rbx_reflection::{ClassTag, ReflectionDatabase};
 use roblox_install::RobloxStudio;
 use std::{env, path::PathBuf, process::Command};
 
-use crate::Properties;
+use crate::{config::Config, logger::LogFormat, processes, Properties};
 
 /// Returns the `.vasc` directory
 pub fn get_vasc_dir() -> Result<PathBuf> {
@@ -35,6 +35,39 @@ pub fn get_plugin_path() -> Result<PathBuf> {
 	Ok(RobloxStudio::locate()?.plugins_path().join("Vasc.rbxm"))
 }
 
+/// Returns all plugin install locations, default Studio
+/// one first, followed by the ones set in `plugin_paths`
+pub fn get_plugin_paths() -> Result<Vec<PathBuf>> {
+	let mut paths = vec![];
+
+	match get_plugin_path() {
+		Ok(path) => paths.push(path),
+		Err(err) => trace!("Failed to locate default plugin path: {err}"),
+	}
+
+	for path in env::split_paths(&Config::new().plugin_paths) {
+		if path.as_os_str().is_empty() {
+			continue;
+		}
+
+		let path = if path.extension().is_some_and(|ext| ext == "rbxm") {
+			path
+		} else {
+			path.join("Vasc.rbxm")
+		};
+
+		if !paths.contains(&path) {
+			paths.push(path);
+		}
+	}
+
+	if paths.is_empty() {
+		bail!("Failed to find any plugin install location, set it with `plugin_paths` setting");
+	}
+
+	Ok(paths)
+}
+
 /// Checks if the given `class` is a service
 pub fn is_service(class: &str) -> bool {
 	let descriptor = get_reflection_database().classes.get(class);
@@ -55,23 +88,7 @@ pub fn is_script(class: &str) -> bool {
 
 /// Kills the process with the given `pid`
 pub fn kill_process(pid: u32) {
-	#[cfg(not(target_os = "windows"))]
-	{
-		// Kill main process
-		Command::new("kill").arg(pid.to_string()).output().ok();
-
-		// Kill child processes
-		Command::new("pkill").arg("-P").arg(pid.to_string()).output().ok();
-	}
-
-	// Kill both main and child processes
-	#[cfg(target_os = "windows")]
-	Command::new("TASKKILL")
-		.arg("/F")
-		.arg("/T")
-		.args(["/PID", &pid.to_string()])
-		.output()
-		.ok();
+	processes::terminate(pid);
 }
 
 pub fn process_exists(pid: u32) -> bool {
@@ -136,6 +153,16 @@ pub fn env_log_style() -> WriteStyle {
 	}
 }
 
+/// Returns the `RUST_LOG_FORMAT` environment variable
+pub fn env_log_format() -> LogFormat {
+	let log_format = env::var("RUST_LOG_FORMAT").unwrap_or("text".into());
+
+	match log_format.as_str() {
+		"json" => LogFormat::Json,
+		_ => LogFormat::Text,
+	}
+}
+
 /// Returns the `RUST_BACKTRACE` environment variable
 pub fn env_backtrace() -> bool {
 	let backtrace = env::var("RUST_BACKTRACE").unwrap_or("0".into());
@@ -169,6 +196,36 @@ pub fn get_json_formatter() -> JsonFormatter<'static> {
 		.with_max_decimals(4)
 }
 
+/// Returns JSON parse error with the offending line and a caret pointing at the column
+pub fn format_json_error(source: &str, err: &serde_json::Error) -> String {
+	let Some(line) = source.lines().nth(err.line().saturating_sub(1)) else {
+		return err.to_string();
+	};
+
+	// serde_json reports column in bytes, so the prefix has to be cut by bytes too
+	let mut column = err.column().saturating_sub(1).min(line.len());
+
+	while !line.is_char_boundary(column) {
+		column -= 1;
+	}
+
+	let number = err.line().to_string();
+	let caret = line[..column]
+		.chars()
+		.map(|char| if char == '\t' { "    " } else { " " })
+		.collect::<String>();
+
+	format!(
+		"{}\n\n {} | {}\n {} | {}{}",
+		err,
+		number,
+		line.replace('\t', "    "),
+		" ".repeat(number.len()),
+		caret,
+		"^".bold()
+	)
+}
+
 /// Returns local or bundled reflection database
 pub fn get_reflection_database() -> &'static ReflectionDatabase<'static> {
 	rbx_reflection_database::get_local()
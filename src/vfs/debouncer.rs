@@ -1,10 +1,12 @@
-use crossbeam_channel::Receiver;
-use log::trace;
-use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
-use notify_debouncer_full::{new_debouncer, DebouncedEvent, Debouncer, FileIdMap};
+use crossbeam_channel::{Receiver, Sender};
+use log::{trace, warn};
+use notify::{EventKind, PollWatcher, RecommendedWatcher, RecursiveMode, Watcher};
+use notify_debouncer_full::{
+	new_debouncer, new_debouncer_opt, DebounceEventResult, DebouncedEvent, Debouncer, FileIdMap,
+};
 use std::{
 	io::{self, Result},
-	path::Path,
+	path::{Path, PathBuf},
 	sync::{mpsc, Arc, RwLock},
 	thread::Builder,
 	time::{Duration, Instant},
@@ -17,17 +19,28 @@ use notify::event::DataChange;
 use notify::event::ModifyKind;
 
 #[cfg(target_os = "linux")]
-use {
-	notify::event::{AccessKind, AccessMode, RenameMode},
-	std::path::PathBuf,
-};
+use notify::event::{AccessKind, AccessMode, RenameMode};
 
 use super::VfsEvent;
-use crate::constants::SYNCBACK_DEBOUNCE_TIME;
+use crate::{config::Config, constants::SYNCBACK_DEBOUNCE_TIME};
 
 #[cfg(target_os = "linux")]
 const DEBOUNCE_TIME: Duration = Duration::from_micros(500);
 
+#[cfg(not(target_os = "windows"))]
+const NETWORK_FILE_SYSTEMS: [&str; 10] = [
+	"nfs",
+	"nfs4",
+	"cifs",
+	"smb2",
+	"smb3",
+	"smbfs",
+	"afpfs",
+	"webdav",
+	"9p",
+	"fuse.sshfs",
+];
+
 macro_rules! event_path {
 	($event:expr) => {
 		$event.paths.first().unwrap().to_owned()
@@ -42,7 +55,11 @@ struct DebounceContext {
 
 pub struct VfsDebouncer {
 	inner: Debouncer<RecommendedWatcher, FileIdMap>,
+	/// Fallback for paths where OS events are unreliable, created on demand
+	poll: Option<Debouncer<PollWatcher, FileIdMap>>,
+	polled_paths: Vec<PathBuf>,
 	pause_state: Arc<RwLock<(bool, Instant)>>,
+	sender: Sender<VfsEvent>,
 	receiver: Receiver<VfsEvent>,
 }
 
@@ -54,44 +71,15 @@ impl VfsDebouncer {
 		let debouncer = new_debouncer(Duration::from_millis(100), None, inner_sender, false).unwrap();
 
 		let pause_state = Arc::new(RwLock::new((false, Instant::now())));
-		let local_pause_state = pause_state.clone();
-
-		Builder::new()
-			.name("debouncer".into())
-			.spawn(move || {
-				#[cfg(target_os = "linux")]
-				let mut context = DebounceContext {
-					time: Instant::now(),
-					path: PathBuf::new(),
-				};
 
-				for events in inner_receiver {
-					let (is_paused, timestamp) = *local_pause_state.read().unwrap();
-
-					if is_paused || timestamp.elapsed() < SYNCBACK_DEBOUNCE_TIME {
-						continue;
-					}
-
-					for event in events.unwrap() {
-						trace!("Debouncing event, paths: {:?}, kind: {:?}", event.paths, event.kind);
-
-						#[cfg(not(target_os = "linux"))]
-						if let Some(event) = debounce(&event) {
-							sender.send(event).unwrap();
-						}
-
-						#[cfg(target_os = "linux")]
-						if let Some(event) = debounce(&event, &mut context) {
-							sender.send(event).unwrap();
-						}
-					}
-				}
-			})
-			.unwrap();
+		spawn_handler(inner_receiver, sender.clone(), pause_state.clone(), false);
 
 		Self {
 			inner: debouncer,
+			poll: None,
+			polled_paths: Vec::new(),
 			pause_state,
+			sender,
 			receiver,
 		}
 	}
@@ -103,6 +91,42 @@ impl VfsDebouncer {
 			RecursiveMode::NonRecursive
 		};
 
+		let (use_polling, interval) = {
+			let config = Config::new();
+			(config.use_polling, Duration::from_millis(config.polling_interval))
+		};
+
+		if use_polling || is_network_path(path) {
+			// Polling with no delay would keep rescanning the tree in a busy loop
+			if interval.is_zero() {
+				return Err(io::Error::new(
+					io::ErrorKind::InvalidInput,
+					"Invalid polling_interval setting: 0, it has to be at least 1 ms",
+				));
+			}
+
+			if !use_polling {
+				warn!(
+					"{} is on a network file system, detecting changes by scanning it every {:?}",
+					path.display(),
+					interval
+				);
+			}
+
+			if self.poll.is_none() {
+				self.poll = Some(self.new_poll_debouncer(interval)?);
+			}
+
+			let poll = self.poll.as_mut().unwrap();
+
+			poll.watcher().watch(path, recursive).map_err(map_error)?;
+			poll.cache().add_root(path, recursive);
+
+			self.polled_paths.push(path.to_owned());
+
+			return Ok(());
+		}
+
 		self.inner.watcher().watch(path, recursive).map_err(map_error)?;
 		self.inner.cache().add_root(path, recursive);
 
@@ -110,12 +134,46 @@ impl VfsDebouncer {
 	}
 
 	pub fn unwatch(&mut self, path: &Path) -> Result<()> {
+		if let Some(index) = self.polled_paths.iter().position(|polled| polled == path) {
+			self.polled_paths.remove(index);
+
+			if let Some(poll) = &mut self.poll {
+				poll.watcher().unwatch(path).map_err(map_error)?;
+				poll.cache().remove_root(path);
+			}
+
+			return Ok(());
+		}
+
 		self.inner.watcher().unwatch(path).map_err(map_error)?;
 		self.inner.cache().remove_root(path);
 
 		Ok(())
 	}
 
+	fn new_poll_debouncer(&self, interval: Duration) -> Result<Debouncer<PollWatcher, FileIdMap>> {
+		let (inner_sender, inner_receiver) = mpsc::channel();
+
+		// Comparing contents catches edits that coarse or cached mtimes would hide
+		let config = notify::Config::default()
+			.with_poll_interval(interval)
+			.with_compare_contents(true);
+
+		let debouncer = new_debouncer_opt::<_, PollWatcher, FileIdMap>(
+			Duration::from_millis(100),
+			None,
+			inner_sender,
+			false,
+			FileIdMap::new(),
+			config,
+		)
+		.map_err(map_error)?;
+
+		spawn_handler(inner_receiver, self.sender.clone(), self.pause_state.clone(), true);
+
+		Ok(debouncer)
+	}
+
 	pub fn pause(&mut self) {
 		*self.pause_state.write().unwrap() = (true, Instant::now());
 	}
@@ -129,6 +187,116 @@ impl VfsDebouncer {
 	}
 }
 
+fn spawn_handler(
+	receiver: mpsc::Receiver<DebounceEventResult>,
+	sender: Sender<VfsEvent>,
+	pause_state: Arc<RwLock<(bool, Instant)>>,
+	polling: bool,
+) {
+	Builder::new()
+		.name("debouncer".into())
+		.spawn(move || {
+			#[cfg(target_os = "linux")]
+			let mut context = DebounceContext {
+				time: Instant::now(),
+				path: PathBuf::new(),
+			};
+
+			for events in receiver {
+				let (is_paused, timestamp) = *pause_state.read().unwrap();
+
+				if is_paused || timestamp.elapsed() < SYNCBACK_DEBOUNCE_TIME {
+					continue;
+				}
+
+				for event in events.unwrap() {
+					trace!("Debouncing event, paths: {:?}, kind: {:?}", event.paths, event.kind);
+
+					if polling {
+						if let Some(event) = debounce_poll(&event) {
+							sender.send(event).unwrap();
+						}
+
+						continue;
+					}
+
+					#[cfg(not(target_os = "linux"))]
+					if let Some(event) = debounce(&event) {
+						sender.send(event).unwrap();
+					}
+
+					#[cfg(target_os = "linux")]
+					if let Some(event) = debounce(&event, &mut context) {
+						sender.send(event).unwrap();
+					}
+				}
+			}
+		})
+		.unwrap();
+}
+
+/// Whether the path is located on a network file system (NFS, SMB, etc.)
+/// where mtimes are coarse or cached and OS events are unreliable
+fn is_network_path(path: &Path) -> bool {
+	let Ok(path) = path.canonicalize() else {
+		return false;
+	};
+
+	#[cfg(target_os = "windows")]
+	{
+		// Mapped network drives are not detected, `use_polling` setting covers them
+		path.to_string_lossy().starts_with(r"\\?\UNC\")
+	}
+
+	#[cfg(not(target_os = "windows"))]
+	{
+		get_mounts()
+			.into_iter()
+			.filter(|(mount_point, _)| path.starts_with(mount_point))
+			.max_by_key(|(mount_point, _)| mount_point.as_os_str().len())
+			.is_some_and(|(_, file_system)| NETWORK_FILE_SYSTEMS.contains(&file_system.as_str()))
+	}
+}
+
+/// Returns mount points with their file system types
+#[cfg(target_os = "linux")]
+fn get_mounts() -> Vec<(PathBuf, String)> {
+	let Ok(mounts) = std::fs::read_to_string("/proc/mounts") else {
+		return Vec::new();
+	};
+
+	mounts
+		.lines()
+		.filter_map(|line| {
+			let mut fields = line.split_whitespace().skip(1);
+			let mount_point = fields.next()?.replace("\\040", " ");
+			let file_system = fields.next()?.to_owned();
+
+			Some((PathBuf::from(mount_point), file_system))
+		})
+		.collect()
+}
+
+/// Returns mount points with their file system types,
+/// parsed from `<device> on <mount point> (<type>, ...)` lines
+#[cfg(target_os = "macos")]
+fn get_mounts() -> Vec<(PathBuf, String)> {
+	let Ok(output) = std::process::Command::new("mount").output() else {
+		return Vec::new();
+	};
+
+	String::from_utf8_lossy(&output.stdout)
+		.lines()
+		.filter_map(|line| {
+			let (_, rest) = line.split_once(" on ")?;
+			let (mount_point, options) = rest.rsplit_once(" (")?;
+			let file_system = options.split([',', ')']).next()?.trim().to_owned();
+
+			Some((PathBuf::from(mount_point), file_system))
+		})
+		.collect()
+}
+
 fn map_error(err: notify::Error) -> io::Error {
 	match err.kind {
 		notify::ErrorKind::Io(err) => err,
@@ -209,6 +377,18 @@ fn debounce(event: &DebouncedEvent, context: &mut DebounceContext) -> Option<Vfs
 	}
 }
 
+/// Polling watcher reports plain create, remove and modify events on every platform
+fn debounce_poll(event: &DebouncedEvent) -> Option<VfsEvent> {
+	let path = event.paths.first()?.to_owned();
+
+	match event.kind {
+		EventKind::Create(_) if path.exists() => Some(VfsEvent::Create(path)),
+		EventKind::Remove(_) => Some(VfsEvent::Delete(path)),
+		EventKind::Modify(_) if path.is_file() => Some(VfsEvent::Write(path)),
+		_ => None,
+	}
+}
+
 #[cfg(target_os = "windows")]
 fn debounce(event: &DebouncedEvent) -> Option<VfsEvent> {
 	match event.kind {
@@ -1,7 +1,8 @@
 use crossbeam_channel::Receiver;
+use log::warn;
 use std::{
-	fs,
-	io::{Error, Result},
+	fs::{self, File},
+	io::{Error, Result, Write},
 	path::{Path, PathBuf},
 };
 
@@ -41,16 +42,63 @@ impl VfsBackend for StdBackend {
 
 	fn read_dir(&self, path: &Path) -> Result<Vec<PathBuf>> {
 		let mut paths = Vec::new();
+		let mut ancestors = None;
 
 		for entry in fs::read_dir(path)? {
-			paths.push(entry?.path());
+			let entry = entry?;
+			let entry_path = entry.path();
+
+			// Symlinks and junctions pointing back up the tree would make every walker recurse forever
+			if entry.file_type()?.is_symlink() && entry_path.is_dir() {
+				let target = fs::canonicalize(&entry_path)?;
+				let ancestors = ancestors.get_or_insert_with(|| canonical_ancestors(path));
+
+				if ancestors.iter().any(|ancestor| ancestor.starts_with(&target)) {
+					warn!("Skipping {}: it links to one of its parent directories", entry_path.display());
+					continue;
+				}
+			}
+
+			paths.push(entry_path);
 		}
 
 		Ok(paths)
 	}
 
+	/// Writes to a temporary file that replaces the target once it is
+	/// synced to disk, so a crash or full disk never leaves a truncated file
 	fn write(&mut self, path: &Path, contents: &[u8]) -> Result<()> {
-		fs::write(path, contents)
+		// Write through symlinks instead of replacing them with a regular file
+		let path = fs::canonicalize(path).unwrap_or_else(|_| path.to_owned());
+
+		// Rewriting identical contents would only bump the modification time
+		// and make editors and other watchers reload the file for nothing
+		if fs::read(&path).is_ok_and(|current| current == contents) {
+			return Ok(());
+		}
+
+		let permissions = fs::metadata(&path).map(|metadata| metadata.permissions()).ok();
+
+		let name = path.file_name().unwrap_or_default().to_string_lossy();
+		let temp_path = path.with_file_name(format!(".{name}.vasc-tmp"));
+
+		let result = (|| {
+			let mut file = File::create(&temp_path)?;
+			file.write_all(contents)?;
+
+			if let Some(permissions) = permissions {
+				file.set_permissions(permissions)?;
+			}
+
+			file.sync_all()?;
+			fs::rename(&temp_path, &path)
+		})();
+
+		if result.is_err() {
+			fs::remove_file(&temp_path).ok();
+		}
+
+		result
 	}
 
 	fn create_dir(&mut self, path: &Path) -> Result<()> {
@@ -130,3 +178,9 @@ impl VfsBackend for StdBackend {
 		self.debouncer.receiver()
 	}
 }
+
+/// Returns canonical paths of the directory and all its parents,
+/// which can differ from the logical ones when walking through symlinks
+fn canonical_ancestors(path: &Path) -> Vec<PathBuf> {
+	path.ancestors().filter_map(|path| fs::canonicalize(path).ok()).collect()
+}
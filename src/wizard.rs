@@ -0,0 +1,56 @@
+use anyhow::Result;
+use colored::Colorize;
+use log::debug;
+
+use crate::{config::Config, installer, logger, t, util, vasc_info};
+
+const CHANNELS: [&str; 2] = ["stable", "beta"];
+
+/// Whether this is the first invocation of Vasc on this machine
+pub fn is_first_run() -> bool {
+	util::get_vasc_dir().map(|dir| !dir.exists()).unwrap_or(false)
+}
+
+/// Walk user through the initial setup, install everything
+/// that was selected and write the global config file
+pub fn run(is_managed: bool) -> Result<()> {
+	vasc_info!("{}", t!("wizard.welcome", command = "vasc config".bold()));
+
+	let defaults = Config::default();
+	let mut config = defaults.clone();
+
+	config.install_plugin = logger::prompt(&t!("wizard.install_plugin"), defaults.install_plugin);
+
+	if config.install_plugin {
+		let channels = CHANNELS.map(String::from);
+		let default = CHANNELS
+			.iter()
+			.position(|channel| *channel == defaults.update_channel)
+			.unwrap_or_default();
+
+		config.update_channel = channels[logger::select(&t!("wizard.update_channel"), &channels, default)].clone();
+	}
+
+	config.update_templates = logger::prompt(&t!("wizard.update_templates"), defaults.update_templates);
+
+	config.check_updates = logger::prompt(&t!("wizard.check_updates"), defaults.check_updates);
+
+	if config.check_updates {
+		config.auto_update = logger::prompt(&t!("wizard.auto_update"), defaults.auto_update);
+	}
+
+	installer::verify(is_managed, config.install_plugin, config.update_channel == "beta")?;
+
+	let path = util::get_vasc_dir()?.join("config.toml");
+
+	if config == defaults {
+		debug!("Setup left all settings at their defaults, skipping config file");
+	} else {
+		config.save(&path)?;
+		Config::load()?;
+	}
+
+	vasc_info!("{}", t!("wizard.completed", command = "vasc init".bold()));
+
+	Ok(())
+}
@@ -12,6 +12,7 @@ use crate::{
 	config::Config,
 	ext::PathExt,
 	program::{Program, ProgramName},
+	templates::{self, Variables},
 	util, vasc_info, vasc_warn,
 };
 
@@ -26,16 +27,20 @@ pub struct WorkspaceConfig<'a> {
 	pub project: &'a Path,
 	pub template: &'a str,
 	pub license: WorkspaceLicense<'a>,
+	/// Name from the `author` setting or the username, used in wally.toml and licenses
+	pub author: &'a str,
 	pub git: bool,
 	pub wally: bool,
 	pub selene: bool,
 	pub docs: bool,
 	pub rojo_mode: bool,
 	pub use_lua: bool,
+	pub examples: bool,
+	pub variables: Variables,
 }
 
-pub fn init(workspace: WorkspaceConfig) -> Result<()> {
-	let template_dir = util::get_vasc_dir()?.join("templates").join(workspace.template);
+pub fn init(mut workspace: WorkspaceConfig) -> Result<()> {
+	let template_dir = templates::get_template_dir(workspace.template)?;
 
 	if !template_dir.exists() {
 		bail!("Template {} does not exist", workspace.template.bold())
@@ -44,16 +49,30 @@ pub fn init(workspace: WorkspaceConfig) -> Result<()> {
 	let workspace_dir = workspace.project.get_parent();
 	let project_name = workspace_dir.get_name();
 
+	let examples = match templates::get_manifest(&template_dir)? {
+		Some(manifest) => {
+			workspace
+				.variables
+				.set_files(join_all(&template_dir, &manifest.templated));
+			join_all(&template_dir, &manifest.examples)
+		}
+		None => vec![],
+	};
+
 	if !workspace_dir.exists() {
 		fs::create_dir_all(workspace_dir)?;
 	}
 
-	for entry in fs::read_dir(template_dir)? {
+	for entry in fs::read_dir(&template_dir)? {
 		let entry = entry?;
 
 		let path = entry.path();
 		let name = path.get_name();
 
+		if name == templates::MANIFEST_NAME {
+			continue;
+		}
+
 		let new_path = if name == "project.json" {
 			workspace.project.to_owned()
 		} else {
@@ -65,9 +84,11 @@ pub fn init(workspace: WorkspaceConfig) -> Result<()> {
 		}
 
 		match name {
-			"project.json" => {
-				let contents = fs::read_to_string(path)?;
+			// Additional `*.project.json` files describe other places of the workspace
+			name if name == "project.json" || name.ends_with(".project.json") => {
+				let contents = fs::read_to_string(&path)?;
 				let contents = contents.replace("$name", project_name);
+				let contents = workspace.variables.substitute_file(&path, contents);
 
 				if workspace.wally {
 					fs::write(new_path, contents)?;
@@ -97,10 +118,11 @@ pub fn init(workspace: WorkspaceConfig) -> Result<()> {
 			}
 			"wally.toml" => {
 				if workspace.wally || workspace.template == "package" {
-					let contents = fs::read_to_string(path)?;
+					let contents = fs::read_to_string(&path)?;
 					let contents = contents.replace("$name", &project_name.to_lowercase());
-					let contents = contents.replace("$author", &util::get_username().to_lowercase());
+					let contents = contents.replace("$author", &workspace.author.to_lowercase());
 					let contents = contents.replace("$license", workspace.license.inner);
+					let contents = workspace.variables.substitute_file(&path, contents);
 
 					fs::write(new_path, contents)?;
 				}
@@ -113,8 +135,9 @@ pub fn init(workspace: WorkspaceConfig) -> Result<()> {
 			_ => match path.get_stem() {
 				"README" | "CHANGELOG" => {
 					if workspace.docs {
-						let contents = fs::read_to_string(path)?;
+						let contents = fs::read_to_string(&path)?;
 						let contents = contents.replace("$name", project_name);
+						let contents = workspace.variables.substitute_file(&path, contents);
 
 						fs::write(new_path, contents)?;
 					}
@@ -122,14 +145,14 @@ pub fn init(workspace: WorkspaceConfig) -> Result<()> {
 				"LICENSE" => {
 					if workspace.docs || workspace.license.force {
 						let fallback = fs::read_to_string(path)?;
-						add_license(&new_path, workspace.license.inner, &fallback)?;
+						add_license(&new_path, workspace.license.inner, workspace.author, &fallback)?;
 					}
 				}
 				_ => {
 					if path.is_dir() {
-						copy_dir(&path, &new_path, workspace.rojo_mode, workspace.use_lua)?;
+						copy_dir(&path, &new_path, &mut workspace, &examples)?;
 					} else {
-						fs::copy(path, new_path)?;
+						copy_file(&path, &new_path, &mut workspace.variables)?;
 					}
 				}
 			},
@@ -143,7 +166,7 @@ pub fn init(workspace: WorkspaceConfig) -> Result<()> {
 	Ok(())
 }
 
-pub fn init_ts(workspace: WorkspaceConfig) -> Result<Option<PathBuf>> {
+pub fn init_ts(mut workspace: WorkspaceConfig) -> Result<Option<PathBuf>> {
 	let package_manager = &Config::new().package_manager;
 
 	vasc_info!("Waiting for {}..", package_manager.bold());
@@ -197,7 +220,7 @@ pub fn init_ts(workspace: WorkspaceConfig) -> Result<Option<PathBuf>> {
 		return Ok(None);
 	}
 
-	let template_dir = util::get_vasc_dir()?.join("templates").join(template);
+	let template_dir = templates::get_template_dir(template)?;
 
 	if !template_dir.exists() {
 		vasc_warn!(
@@ -210,6 +233,12 @@ pub fn init_ts(workspace: WorkspaceConfig) -> Result<Option<PathBuf>> {
 
 	let project_name = project.get_name();
 
+	if let Some(manifest) = templates::get_manifest(&template_dir)? {
+		workspace
+			.variables
+			.set_files(join_all(&template_dir, &manifest.templated));
+	}
+
 	for entry in fs::read_dir(template_dir)? {
 		let entry = entry?;
 
@@ -221,28 +250,24 @@ pub fn init_ts(workspace: WorkspaceConfig) -> Result<Option<PathBuf>> {
 		}
 
 		match path.get_stem() {
-			"wally" => {
-				if workspace.wally || template == "package" {
-					let contents = fs::read_to_string(path)?;
-					let contents = contents.replace("$name", &project_name.to_lowercase());
-					let contents = contents.replace("$author", &util::get_username().to_lowercase());
+			"wally" if workspace.wally || template == "package" => {
+				let contents = fs::read_to_string(&path)?;
+				let contents = contents.replace("$name", &project_name.to_lowercase());
+				let contents = contents.replace("$author", &workspace.author.to_lowercase());
+				let contents = workspace.variables.substitute_file(&path, contents);
 
-					fs::write(new_path, contents)?;
-				}
+				fs::write(new_path, contents)?;
 			}
-			"README" | "CHANGELOG" => {
-				if workspace.docs {
-					let contents = fs::read_to_string(path)?;
-					let contents = contents.replace("$name", project_name);
+			"README" | "CHANGELOG" if workspace.docs => {
+				let contents = fs::read_to_string(&path)?;
+				let contents = contents.replace("$name", project_name);
+				let contents = workspace.variables.substitute_file(&path, contents);
 
-					fs::write(new_path, contents)?;
-				}
+				fs::write(new_path, contents)?;
 			}
-			"LICENSE" => {
-				if workspace.docs || workspace.license.force {
-					let fallback = fs::read_to_string(path)?;
-					add_license(&new_path, workspace.license.inner, &fallback)?;
-				}
+			"LICENSE" if workspace.docs || workspace.license.force => {
+				let fallback = fs::read_to_string(path)?;
+				add_license(&new_path, workspace.license.inner, workspace.author, &fallback)?;
 			}
 
 			_ => {}
@@ -266,7 +291,7 @@ pub fn initialize_repo(directory: &Path) -> Result<()> {
 	Ok(())
 }
 
-fn add_license(path: &Path, license: &str, fallback: &str) -> Result<()> {
+fn add_license(path: &Path, license: &str, name: &str, fallback: &str) -> Result<()> {
 	trace!("Getting {license} license template..");
 
 	let url = format!("https://api.github.com/licenses/{license}");
@@ -288,29 +313,28 @@ fn add_license(path: &Path, license: &str, fallback: &str) -> Result<()> {
 		}
 	}();
 
-	let name = util::get_username();
 	let year = chrono::Utc::now().year();
 
 	match license_template {
 		Ok(license) => {
 			// Apache
 			let license = license.replace("[yyyy]", &year.to_string());
-			let license = license.replace("[name of copyright owner]", &name);
+			let license = license.replace("[name of copyright owner]", name);
 
 			// MIT & BSD
 			let license = license.replace("[year]", &year.to_string());
-			let license = license.replace("[fullname]", &name);
+			let license = license.replace("[fullname]", name);
 
 			// GNU
 			let license = license.replace("<year>", &year.to_string());
-			let license = license.replace("<name of author>", &name);
+			let license = license.replace("<name of author>", name);
 
 			fs::write(path, license)?;
 		}
 		Err(err) => {
 			let license = fallback.replace("$license", license);
 			let license = license.replace("$year", &year.to_string());
-			let license = license.replace("$owner", &name);
+			let license = license.replace("$owner", name);
 
 			fs::write(path, license)?;
 
@@ -323,7 +347,7 @@ fn add_license(path: &Path, license: &str, fallback: &str) -> Result<()> {
 	Ok(())
 }
 
-fn copy_dir(from: &Path, to: &Path, rojo_mode: bool, use_lua: bool) -> Result<()> {
+fn copy_dir(from: &Path, to: &Path, workspace: &mut WorkspaceConfig, examples: &[PathBuf]) -> Result<()> {
 	if !to.exists() {
 		fs::create_dir_all(to)?;
 	}
@@ -334,20 +358,37 @@ fn copy_dir(from: &Path, to: &Path, rojo_mode: bool, use_lua: bool) -> Result<()
 		let path = entry.path();
 		let mut name = path.get_name().to_owned();
 
-		if name.starts_with(".src") && rojo_mode {
+		if name.starts_with(".src") && workspace.rojo_mode {
 			name = name.replace(".src", "init");
 		}
 
-		if name.ends_with(".luau") && use_lua {
+		if name.ends_with(".luau") && workspace.use_lua {
 			name = name.replace(".luau", ".lua");
 		}
 
 		if path.is_dir() {
-			copy_dir(&path, &to.join(name), rojo_mode, use_lua)?;
+			copy_dir(&path, &to.join(name), workspace, examples)?;
+		} else if !workspace.examples && examples.contains(&path) {
+			continue;
 		} else if name != ".gitkeep" {
-			fs::copy(&path, to.join(name))?;
+			copy_file(&path, &to.join(name), &mut workspace.variables)?;
 		}
 	}
 
 	Ok(())
 }
+
+fn copy_file(from: &Path, to: &Path, variables: &mut Variables) -> Result<()> {
+	if variables.applies_to(from) {
+		let contents = fs::read_to_string(from)?;
+		fs::write(to, variables.substitute(&contents))?;
+	} else {
+		fs::copy(from, to)?;
+	}
+
+	Ok(())
+}
+
+fn join_all(dir: &Path, paths: &[String]) -> Vec<PathBuf> {
+	paths.iter().map(|path| dir.join(path)).collect()
+}
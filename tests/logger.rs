@@ -0,0 +1,154 @@
+mod log_file {
+	use std::{env, fs, path::PathBuf};
+	use vasc::logger::LogFile;
+
+	fn dir(name: &str) -> PathBuf {
+		let dir = env::temp_dir().join("vasc-tests").join(name);
+
+		fs::remove_dir_all(&dir).ok();
+		fs::create_dir_all(&dir).unwrap();
+
+		dir
+	}
+
+	#[test]
+	fn below_max_size() {
+		let path = dir("log-below").join("vasc.log");
+		let mut file = LogFile::new(path.clone(), 100, 3).unwrap();
+
+		file.write("first\n").unwrap();
+		file.write("second\n").unwrap();
+
+		assert_eq!(fs::read_to_string(&path).unwrap(), "first\nsecond\n");
+		assert!(!path.with_extension("log.1").exists());
+	}
+
+	#[test]
+	fn rotation() {
+		let path = dir("log-rotation").join("vasc.log");
+		let mut file = LogFile::new(path.clone(), 10, 2).unwrap();
+
+		file.write("0123456789\n").unwrap();
+		file.write("abcdefghij\n").unwrap();
+		file.write("last\n").unwrap();
+
+		assert_eq!(fs::read_to_string(&path).unwrap(), "last\n");
+		assert_eq!(
+			fs::read_to_string(path.with_extension("log.1")).unwrap(),
+			"abcdefghij\n"
+		);
+		assert_eq!(
+			fs::read_to_string(path.with_extension("log.2")).unwrap(),
+			"0123456789\n"
+		);
+	}
+
+	#[test]
+	fn max_files() {
+		let path = dir("log-max-files").join("vasc.log");
+		let mut file = LogFile::new(path.clone(), 1, 2).unwrap();
+
+		for index in 0..5 {
+			file.write(&format!("{index}\n")).unwrap();
+		}
+
+		assert_eq!(fs::read_to_string(&path).unwrap(), "4\n");
+		assert_eq!(fs::read_to_string(path.with_extension("log.1")).unwrap(), "3\n");
+		assert_eq!(fs::read_to_string(path.with_extension("log.2")).unwrap(), "2\n");
+		assert!(!path.with_extension("log.3").exists());
+	}
+
+	#[test]
+	fn existing_file() {
+		let path = dir("log-existing").join("vasc.log");
+		fs::write(&path, "0123456789\n").unwrap();
+
+		LogFile::new(path.clone(), 10, 1).unwrap();
+
+		assert_eq!(fs::read_to_string(&path).unwrap(), "");
+		assert_eq!(
+			fs::read_to_string(path.with_extension("log.1")).unwrap(),
+			"0123456789\n"
+		);
+	}
+}
+
+mod level_filters {
+	use log::{Level, LevelFilter, Record};
+	use std::collections::HashMap;
+	use vasc::logger::LevelFilters;
+
+	fn filters(modules: &[(&str, &str)]) -> (LevelFilters, Vec<String>) {
+		let modules = modules
+			.iter()
+			.map(|(module, level)| (module.to_string(), level.to_string()))
+			.collect::<HashMap<_, _>>();
+
+		LevelFilters::new(LevelFilter::Info, &modules)
+	}
+
+	fn allows(filters: &LevelFilters, module: &str, level: Level) -> bool {
+		filters.allows(
+			&Record::builder()
+				.module_path(Some(module))
+				.target(module)
+				.level(level)
+				.build(),
+		)
+	}
+
+	#[test]
+	fn verbosity() {
+		let (filters, _) = filters(&[]);
+
+		assert!(allows(&filters, "vasc::server", Level::Info));
+		assert!(!allows(&filters, "vasc::server", Level::Debug));
+	}
+
+	#[test]
+	fn short_name() {
+		let (filters, _) = filters(&[("server", "trace")]);
+
+		assert!(allows(&filters, "vasc::server", Level::Trace));
+		assert!(allows(&filters, "vasc::server::ws", Level::Trace));
+		assert!(!allows(&filters, "vasc::core", Level::Debug));
+	}
+
+	#[test]
+	fn full_path() {
+		let (filters, _) = filters(&[("vasc::core", "debug"), ("reqwest", "error")]);
+
+		assert!(allows(&filters, "vasc::core::processor", Level::Debug));
+		assert!(!allows(&filters, "reqwest::blocking", Level::Warn));
+	}
+
+	#[test]
+	fn longest_match() {
+		let (filters, _) = filters(&[("core", "off"), ("core::processor", "debug")]);
+
+		assert!(!allows(&filters, "vasc::core", Level::Error));
+		assert!(allows(&filters, "vasc::core::processor", Level::Debug));
+	}
+
+	#[test]
+	fn module_prefix() {
+		let (filters, _) = filters(&[("server", "debug")]);
+
+		assert!(!allows(&filters, "vasc::servers", Level::Debug));
+	}
+
+	#[test]
+	fn vasc_log() {
+		let (filters, _) = filters(&[("vasc", "off")]);
+
+		assert!(filters.allows(&Record::builder().target("vasc_log").level(Level::Info).build()));
+	}
+
+	#[test]
+	fn invalid() {
+		let (filters, invalid) = filters(&[("server", "loud")]);
+
+		assert_eq!(invalid, vec![String::from("server = loud")]);
+		assert!(!allows(&filters, "vasc::server", Level::Debug));
+	}
+}
@@ -0,0 +1,118 @@
+mod upgrade {
+	use toml::{Table, Value};
+	use vasc::migrations::{self, Migration};
+
+	fn add_a(table: &mut Table) {
+		table.insert("a".into(), Value::Integer(1));
+	}
+
+	fn add_b(table: &mut Table) {
+		table.insert("b".into(), Value::Integer(2));
+	}
+
+	const MIGRATIONS: &[Migration] = &[add_a, add_b];
+
+	fn table(toml: &str) -> Table {
+		toml::from_str(toml).unwrap()
+	}
+
+	#[test]
+	fn unversioned() {
+		let mut table = table("");
+
+		assert!(migrations::upgrade(&mut table, MIGRATIONS));
+		assert_eq!(table, self::table("a = 1\nb = 2"));
+	}
+
+	#[test]
+	fn partial() {
+		let mut table = table("format_version = 1");
+
+		assert!(migrations::upgrade(&mut table, MIGRATIONS));
+		assert_eq!(table, self::table("b = 2"));
+	}
+
+	#[test]
+	fn latest() {
+		let mut table = table("format_version = 2\nc = 3");
+
+		assert!(!migrations::upgrade(&mut table, MIGRATIONS));
+		assert_eq!(table, self::table("c = 3"));
+	}
+
+	#[test]
+	fn newer() {
+		let mut table = table("format_version = 5");
+
+		assert!(!migrations::upgrade(&mut table, MIGRATIONS));
+		assert!(table.is_empty());
+	}
+
+	#[test]
+	fn invalid_version() {
+		let mut table = table("format_version = -1");
+
+		assert!(migrations::upgrade(&mut table, MIGRATIONS));
+		assert_eq!(table, self::table("a = 1\nb = 2"));
+
+		let mut table = self::table("format_version = \"2\"");
+
+		assert!(migrations::upgrade(&mut table, MIGRATIONS));
+		assert_eq!(table, self::table("a = 1\nb = 2"));
+	}
+
+	#[test]
+	fn rename_auto_detect() {
+		let mut table = table("auto_detect = false");
+
+		migrations::upgrade(&mut table, migrations::CONFIG);
+		assert_eq!(table, self::table("detect_project = false"));
+
+		let mut table = self::table("auto_detect = false\ndetect_project = true");
+
+		migrations::upgrade(&mut table, migrations::CONFIG);
+		assert_eq!(table, self::table("detect_project = true"));
+	}
+}
+
+mod state_file {
+	use std::{env, fs, path::PathBuf};
+	use toml::{Table, Value};
+	use vasc::migrations::{self, Migration};
+
+	fn add_a(table: &mut Table) {
+		table.insert("a".into(), Value::Integer(1));
+	}
+
+	const MIGRATIONS: &[Migration] = &[add_a];
+
+	fn path(name: &str) -> PathBuf {
+		let dir = env::temp_dir().join("vasc-tests").join(name);
+
+		fs::remove_dir_all(&dir).ok();
+		fs::create_dir_all(&dir).unwrap();
+
+		dir.join("state.toml")
+	}
+
+	#[test]
+	fn migrated_on_read() {
+		let path = path("migrations-read");
+		fs::write(&path, "b = 2").unwrap();
+
+		let table: Table = migrations::read(&path, MIGRATIONS).unwrap();
+		assert_eq!(table, toml::from_str("a = 1\nb = 2").unwrap());
+
+		let stored: Table = toml::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+		assert_eq!(stored.get("format_version"), Some(&Value::Integer(1)));
+	}
+
+	#[test]
+	fn backup_on_error() {
+		let path = path("migrations-backup");
+		fs::write(&path, "not toml").unwrap();
+
+		assert!(migrations::read::<Table>(&path, MIGRATIONS).is_err());
+		assert_eq!(fs::read_to_string(path.with_extension("toml.bak")).unwrap(), "not toml");
+	}
+}
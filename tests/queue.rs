@@ -0,0 +1,83 @@
+mod drain_changes {
+	use std::{
+		sync::Arc,
+		thread,
+		time::{Duration, Instant},
+	};
+	use vasc::{
+		core::{changes::Changes, queue::Queue},
+		server::{Disconnect, SyncChanges},
+	};
+
+	const WINDOW: Duration = Duration::from_millis(50);
+
+	fn queue() -> Arc<Queue> {
+		let queue = Queue::new();
+		queue.subscribe_internal().unwrap();
+
+		Arc::new(queue)
+	}
+
+	#[test]
+	fn burst() {
+		let queue = queue();
+
+		for _ in 0..3 {
+			queue.push(SyncChanges(Changes::new()), None).unwrap();
+		}
+
+		assert_eq!(queue.drain_changes(0, WINDOW).unwrap(), 3);
+	}
+
+	#[test]
+	fn other_messages() {
+		let queue = queue();
+
+		queue.push(SyncChanges(Changes::new()), None).unwrap();
+		queue.push(Disconnect { message: String::new() }, None).unwrap();
+
+		assert_eq!(queue.drain_changes(0, WINDOW).unwrap(), 1);
+	}
+
+	#[test]
+	fn empty() {
+		let queue = queue();
+		let started = Instant::now();
+
+		assert_eq!(queue.drain_changes(0, WINDOW).unwrap(), 0);
+		assert!(started.elapsed() < WINDOW * 2);
+	}
+
+	#[test]
+	fn batch_cap() {
+		let queue = queue();
+
+		let pusher = {
+			let queue = queue.clone();
+
+			thread::spawn(move || {
+				let started = Instant::now();
+
+				while started.elapsed() < WINDOW * 30 {
+					queue.push(SyncChanges(Changes::new()), None).unwrap();
+					thread::sleep(WINDOW / 5);
+				}
+			})
+		};
+
+		let started = Instant::now();
+		let count = queue.drain_changes(0, WINDOW).unwrap();
+
+		assert!(count > 0);
+		assert!(started.elapsed() < WINDOW * 20);
+
+		pusher.join().unwrap();
+	}
+
+	#[test]
+	fn not_subscribed() {
+		let queue = Queue::new();
+
+		assert!(queue.drain_changes(0, WINDOW).is_err());
+	}
+}
@@ -0,0 +1,215 @@
+mod rate_limiter {
+	use std::{net::IpAddr, thread, time::Duration};
+	use vasc::server::rate_limit::RateLimiter;
+
+	fn ip(address: &str) -> IpAddr {
+		address.parse().unwrap()
+	}
+
+	#[test]
+	fn within_limit() {
+		let limiter = RateLimiter::new(3);
+
+		assert!(limiter.check(ip("127.0.0.1")));
+		assert!(limiter.check(ip("127.0.0.1")));
+		assert!(limiter.check(ip("127.0.0.1")));
+	}
+
+	#[test]
+	fn over_limit() {
+		let limiter = RateLimiter::new(2);
+
+		assert!(limiter.check(ip("127.0.0.1")));
+		assert!(limiter.check(ip("127.0.0.1")));
+		assert!(!limiter.check(ip("127.0.0.1")));
+	}
+
+	#[test]
+	fn per_address() {
+		let limiter = RateLimiter::new(1);
+
+		assert!(limiter.check(ip("127.0.0.1")));
+		assert!(!limiter.check(ip("127.0.0.1")));
+		assert!(limiter.check(ip("::1")));
+	}
+
+	#[test]
+	fn window_reset() {
+		let limiter = RateLimiter::new(1);
+
+		assert!(limiter.check(ip("127.0.0.1")));
+		assert!(!limiter.check(ip("127.0.0.1")));
+
+		thread::sleep(Duration::from_millis(1100));
+
+		assert!(limiter.check(ip("127.0.0.1")));
+	}
+}
+
+mod address {
+	use vasc::server::{format_address, normalize_host};
+
+	#[test]
+	fn normalize() {
+		assert_eq!(normalize_host("[::1]"), "::1");
+		assert_eq!(normalize_host("::1"), "::1");
+		assert_eq!(normalize_host("localhost"), "localhost");
+		assert_eq!(normalize_host("127.0.0.1"), "127.0.0.1");
+		assert_eq!(normalize_host("[::1"), "[::1");
+	}
+
+	#[test]
+	fn ipv4() {
+		assert_eq!(format_address("127.0.0.1", 8000, false), "http://127.0.0.1:8000");
+		assert_eq!(format_address("localhost", 8000, true), "https://localhost:8000");
+	}
+
+	#[test]
+	fn ipv6() {
+		assert_eq!(format_address("::1", 8000, false), "http://[::1]:8000");
+		assert_eq!(format_address("[::1]", 8000, false), "http://[::1]:8000");
+		assert_eq!(format_address("::", 8000, true), "https://[::]:8000");
+		assert_eq!(format_address("fe80::1", 443, true), "https://[fe80::1]:443");
+	}
+}
+
+mod ip_filter {
+	use std::net::IpAddr;
+	use vasc::server::ip_filter::IpFilter;
+
+	fn ip(address: &str) -> IpAddr {
+		address.parse().unwrap()
+	}
+
+	#[test]
+	fn empty() {
+		let filter = IpFilter::new("", "").unwrap();
+
+		assert!(filter.check(ip("192.168.1.10")));
+		assert!(filter.check(ip("2001:db8::1")));
+	}
+
+	#[test]
+	fn allowed() {
+		let filter = IpFilter::new("192.168.1.0/24, 10.0.0.5", "").unwrap();
+
+		assert!(filter.check(ip("192.168.1.10")));
+		assert!(filter.check(ip("10.0.0.5")));
+		assert!(!filter.check(ip("10.0.0.6")));
+		assert!(!filter.check(ip("192.168.2.10")));
+	}
+
+	#[test]
+	fn denied() {
+		let filter = IpFilter::new("192.168.0.0/16", "192.168.1.0/24").unwrap();
+
+		assert!(filter.check(ip("192.168.2.10")));
+		assert!(!filter.check(ip("192.168.1.10")));
+	}
+
+	#[test]
+	fn loopback() {
+		let filter = IpFilter::new("192.168.1.0/24", "127.0.0.0/8, ::1").unwrap();
+
+		assert!(filter.check(ip("127.0.0.1")));
+		assert!(filter.check(ip("::1")));
+	}
+
+	#[test]
+	fn mapped_ipv4() {
+		let filter = IpFilter::new("192.168.1.0/24", "").unwrap();
+
+		assert!(filter.check(ip("::ffff:192.168.1.10")));
+		assert!(!filter.check(ip("::ffff:192.168.2.10")));
+	}
+
+	#[test]
+	fn invalid() {
+		assert!(IpFilter::new("192.168.1.0/33", "").is_err());
+		assert!(IpFilter::new("", "localhost").is_err());
+	}
+}
+
+mod token {
+	use std::{env, fs, path::PathBuf};
+	use vasc::server::{read_token, TOKEN_VAR};
+
+	fn token_file(name: &str, contents: &str) -> PathBuf {
+		let path = env::temp_dir().join(format!("vasc-token-{name}-{}", std::process::id()));
+		fs::write(&path, contents).unwrap();
+
+		#[cfg(unix)]
+		{
+			use std::os::unix::fs::PermissionsExt;
+			fs::set_permissions(&path, fs::Permissions::from_mode(0o600)).unwrap();
+		}
+
+		path
+	}
+
+	#[test]
+	fn from_file() {
+		let path = token_file("file", "secret\n");
+
+		assert_eq!(read_token(Some(&path)).unwrap(), Some(String::from("secret")));
+
+		fs::remove_file(path).unwrap();
+	}
+
+	#[test]
+	fn crlf() {
+		let path = token_file("crlf", "secret\r\n");
+
+		assert_eq!(read_token(Some(&path)).unwrap(), Some(String::from("secret")));
+
+		fs::remove_file(path).unwrap();
+	}
+
+	#[test]
+	fn invalid() {
+		let empty = token_file("empty", "\n");
+		let spaces = token_file("spaces", "two words");
+
+		assert!(read_token(Some(&empty)).is_err());
+		assert!(read_token(Some(&spaces)).is_err());
+
+		fs::remove_file(empty).unwrap();
+		fs::remove_file(spaces).unwrap();
+	}
+
+	#[test]
+	fn missing_file() {
+		assert!(read_token(Some(&env::temp_dir().join("vasc-token-missing"))).is_err());
+	}
+
+	#[cfg(unix)]
+	#[test]
+	fn world_readable() {
+		use std::os::unix::fs::PermissionsExt;
+
+		let path = token_file("readable", "secret");
+		fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+
+		assert!(read_token(Some(&path)).is_err());
+
+		fs::remove_file(path).unwrap();
+	}
+
+	#[test]
+	fn from_env() {
+		let path = token_file("precedence", "from_file");
+
+		env::set_var(TOKEN_VAR, "from_env");
+
+		let from_env = read_token(None).unwrap();
+		let from_file = read_token(Some(&path)).unwrap();
+
+		env::remove_var(TOKEN_VAR);
+
+		assert_eq!(from_env, Some(String::from("from_env")));
+		assert_eq!(from_file, Some(String::from("from_file")));
+		assert_eq!(read_token(None).unwrap(), None);
+
+		fs::remove_file(path).unwrap();
+	}
+}
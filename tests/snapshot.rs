@@ -0,0 +1,52 @@
+mod updated_snapshot {
+	use rbx_dom_weak::{
+		types::{Ref, Variant},
+		ustr,
+	};
+	use vasc::{
+		core::snapshot::{PropertiesDelta, UpdatedSnapshot},
+		Properties,
+	};
+
+	fn properties(values: &[(&str, f32)]) -> Properties {
+		values
+			.iter()
+			.map(|(name, value)| (ustr(name), Variant::Float32(*value)))
+			.collect()
+	}
+
+	#[test]
+	fn full_update() {
+		let mut snapshot = UpdatedSnapshot::new(Ref::new());
+		snapshot.properties = Some(properties(&[("A", 1.0), ("B", 2.0)]));
+
+		let snapshot = snapshot.into_delta();
+
+		assert!(!snapshot.is_delta);
+		assert_eq!(snapshot.properties, Some(properties(&[("A", 1.0), ("B", 2.0)])));
+	}
+
+	#[test]
+	fn delta_update() {
+		let old = properties(&[("A", 1.0), ("B", 2.0), ("C", 3.0)]);
+		let new = properties(&[("A", 1.0), ("B", 5.0)]);
+
+		let mut snapshot = UpdatedSnapshot::new(Ref::new());
+		snapshot.delta = Some(PropertiesDelta::new(&old, &new));
+		snapshot.properties = Some(new);
+
+		let snapshot = snapshot.into_delta();
+
+		assert!(snapshot.is_delta);
+		assert_eq!(snapshot.properties, Some(properties(&[("B", 5.0)])));
+		assert_eq!(snapshot.removed_properties, vec![ustr("C")]);
+	}
+
+	#[test]
+	fn serialized_marker() {
+		let snapshot = UpdatedSnapshot::new(Ref::new());
+		let json = serde_json::to_value(&snapshot).unwrap();
+
+		assert_eq!(json["is_delta"], false);
+	}
+}
@@ -0,0 +1,99 @@
+mod template_dir {
+	use vasc::templates::{get_template_dir, get_templates_dir};
+
+	#[test]
+	fn single_component() {
+		assert_eq!(
+			get_template_dir("place").unwrap(),
+			get_templates_dir().unwrap().join("place")
+		);
+	}
+
+	#[test]
+	fn parent_dir() {
+		assert!(get_template_dir("..").is_err());
+		assert!(get_template_dir("../place").is_err());
+	}
+
+	#[test]
+	fn current_dir() {
+		assert!(get_template_dir(".").is_err());
+		assert!(get_template_dir("").is_err());
+	}
+
+	#[test]
+	fn absolute_path() {
+		assert!(get_template_dir("/tmp").is_err());
+
+		#[cfg(target_os = "windows")]
+		assert!(get_template_dir("C:\\Windows").is_err());
+	}
+
+	#[test]
+	fn nested_path() {
+		assert!(get_template_dir("place/src").is_err());
+	}
+}
+
+mod variables {
+	use std::path::Path;
+	use vasc::templates::Variables;
+
+	fn variables() -> Variables {
+		Variables::new().with("project_name", "Game").with("author", "Vaist")
+	}
+
+	#[test]
+	fn substitute() {
+		assert_eq!(
+			variables().substitute("{{project_name}} by {{ author }}"),
+			"Game by Vaist"
+		);
+	}
+
+	#[test]
+	fn provided_first() {
+		let mut variables = Variables::new()
+			.with_pairs(&[String::from("author=Someone")])
+			.unwrap()
+			.with("author", "Vaist");
+
+		assert_eq!(variables.substitute("{{author}}"), "Someone");
+	}
+
+	#[test]
+	fn invalid_pair() {
+		assert!(Variables::new().with_pairs(&[String::from("author")]).is_err());
+	}
+
+	#[test]
+	fn missing() {
+		assert_eq!(variables().substitute("[{{version}}]"), "[]");
+	}
+
+	#[test]
+	fn not_placeholders() {
+		let contents = "local t = {{1, 2}, {3}} {{ }} {{a-b}}";
+		assert_eq!(variables().substitute(contents), contents);
+	}
+
+	#[test]
+	fn unterminated() {
+		assert_eq!(variables().substitute("{{author}} {{author"), "Vaist {{author");
+	}
+
+	#[test]
+	fn listed_files_only() {
+		let mut variables = variables();
+		variables.set_files(vec![Path::new("README.md").to_owned()]);
+
+		assert_eq!(
+			variables.substitute_file(Path::new("README.md"), String::from("{{project_name}}")),
+			"Game"
+		);
+		assert_eq!(
+			variables.substitute_file(Path::new("src/init.luau"), String::from("{{project_name}}")),
+			"{{project_name}}"
+		);
+	}
+}
@@ -0,0 +1,27 @@
+mod json_error {
+	use vasc::util::format_json_error;
+
+	fn caret(source: &str) -> String {
+		colored::control::set_override(false);
+
+		let err = serde_json::from_str::<serde_json::Value>(source).unwrap_err();
+		let error = format_json_error(source, &err);
+
+		error.lines().last().unwrap().to_owned()
+	}
+
+	#[test]
+	fn ascii() {
+		assert_eq!(caret("{\"a\": x}"), "   |       ^");
+	}
+
+	#[test]
+	fn non_ascii() {
+		assert_eq!(caret("{\"żółć\": x}"), "   |          ^");
+	}
+
+	#[test]
+	fn tabs() {
+		assert_eq!(caret("{\n\t\"a\": x\n}"), "   |          ^");
+	}
+}
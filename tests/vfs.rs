@@ -0,0 +1,175 @@
+#[cfg(unix)]
+mod read_dir {
+	use std::{
+		fs,
+		os::unix::fs::symlink,
+		path::{Path, PathBuf},
+	};
+	use vasc::vfs::Vfs;
+
+	fn temp_dir(name: &str) -> PathBuf {
+		let dir = std::env::temp_dir().join(format!("vasc-vfs-{name}-{}", std::process::id()));
+
+		fs::remove_dir_all(&dir).ok();
+		fs::create_dir_all(&dir).unwrap();
+
+		dir
+	}
+
+	fn names(vfs: &Vfs, path: &Path) -> Vec<String> {
+		let mut names: Vec<String> = vfs
+			.read_dir(path)
+			.unwrap()
+			.iter()
+			.map(|path| path.file_name().unwrap().to_string_lossy().into_owned())
+			.collect();
+
+		names.sort();
+		names
+	}
+
+	#[test]
+	fn parent_link() {
+		let dir = temp_dir("parent");
+		fs::write(dir.join("file.luau"), "").unwrap();
+		symlink(&dir, dir.join("loop")).unwrap();
+
+		assert_eq!(names(&Vfs::new(false), &dir), vec!["file.luau"]);
+
+		fs::remove_dir_all(dir).unwrap();
+	}
+
+	#[test]
+	fn indirect_loop() {
+		let dir = temp_dir("indirect");
+		fs::create_dir_all(dir.join("a")).unwrap();
+		fs::create_dir_all(dir.join("b")).unwrap();
+		symlink(dir.join("b"), dir.join("a").join("to_b")).unwrap();
+		symlink(dir.join("a"), dir.join("b").join("to_a")).unwrap();
+
+		let vfs = Vfs::new(false);
+
+		assert_eq!(names(&vfs, &dir.join("a")), vec!["to_b"]);
+		assert!(names(&vfs, &dir.join("a").join("to_b")).is_empty());
+
+		fs::remove_dir_all(dir).unwrap();
+	}
+
+	#[test]
+	fn sibling_link() {
+		let dir = temp_dir("sibling");
+		fs::create_dir_all(dir.join("shared")).unwrap();
+		fs::create_dir_all(dir.join("src")).unwrap();
+		fs::write(dir.join("shared").join("file.luau"), "").unwrap();
+		symlink(dir.join("shared"), dir.join("src").join("shared")).unwrap();
+
+		let vfs = Vfs::new(false);
+
+		assert_eq!(names(&vfs, &dir.join("src")), vec!["shared"]);
+		assert_eq!(names(&vfs, &dir.join("src").join("shared")), vec!["file.luau"]);
+
+		fs::remove_dir_all(dir).unwrap();
+	}
+}
+
+mod write {
+	use std::{fs, path::PathBuf, thread, time::Duration};
+	use vasc::vfs::Vfs;
+
+	fn temp_dir(name: &str) -> PathBuf {
+		let dir = std::env::temp_dir().join(format!("vasc-vfs-{name}-{}", std::process::id()));
+
+		fs::remove_dir_all(&dir).ok();
+		fs::create_dir_all(&dir).unwrap();
+
+		dir
+	}
+
+	#[test]
+	fn new_file() {
+		let dir = temp_dir("new");
+		let path = dir.join("file.luau");
+
+		Vfs::new(false).write(&path, b"print()").unwrap();
+
+		assert_eq!(fs::read(&path).unwrap(), b"print()");
+		assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+
+		fs::remove_dir_all(dir).unwrap();
+	}
+
+	#[test]
+	fn existing_file() {
+		let dir = temp_dir("existing");
+		let path = dir.join("file.luau");
+		fs::write(&path, "a longer previous source").unwrap();
+
+		Vfs::new(false).write(&path, b"new").unwrap();
+
+		assert_eq!(fs::read(&path).unwrap(), b"new");
+		assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+
+		fs::remove_dir_all(dir).unwrap();
+	}
+
+	#[test]
+	fn same_contents() {
+		let dir = temp_dir("same");
+		let path = dir.join("file.luau");
+		fs::write(&path, "print()").unwrap();
+
+		let modified = fs::metadata(&path).unwrap().modified().unwrap();
+		thread::sleep(Duration::from_millis(20));
+
+		Vfs::new(false).write(&path, b"print()").unwrap();
+
+		assert_eq!(fs::metadata(&path).unwrap().modified().unwrap(), modified);
+
+		fs::remove_dir_all(dir).unwrap();
+	}
+
+	#[test]
+	fn missing_dir() {
+		let dir = temp_dir("missing");
+
+		assert!(Vfs::new(false)
+			.write(&dir.join("missing").join("file.luau"), b"")
+			.is_err());
+
+		fs::remove_dir_all(dir).unwrap();
+	}
+
+	#[cfg(unix)]
+	#[test]
+	fn permissions() {
+		use std::os::unix::fs::PermissionsExt;
+
+		let dir = temp_dir("permissions");
+		let path = dir.join("script.sh");
+		fs::write(&path, "").unwrap();
+		fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+
+		Vfs::new(false).write(&path, b"echo").unwrap();
+
+		assert_eq!(fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o755);
+
+		fs::remove_dir_all(dir).unwrap();
+	}
+
+	#[cfg(unix)]
+	#[test]
+	fn symlink() {
+		let dir = temp_dir("symlink");
+		let target = dir.join("target.luau");
+		let link = dir.join("link.luau");
+		fs::write(&target, "").unwrap();
+		std::os::unix::fs::symlink(&target, &link).unwrap();
+
+		Vfs::new(false).write(&link, b"linked").unwrap();
+
+		assert!(fs::symlink_metadata(&link).unwrap().file_type().is_symlink());
+		assert_eq!(fs::read(&target).unwrap(), b"linked");
+
+		fs::remove_dir_all(dir).unwrap();
+	}
+}